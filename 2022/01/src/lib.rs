@@ -0,0 +1,70 @@
+use aoc_common::{Answers, Error, ParseError, Solution};
+use priority_queue::PriorityQueue;
+use std::io::BufRead;
+
+/// The `n` largest calorie totals carried by a single elf, largest first.
+pub fn top_totals(input: impl BufRead, n: usize) -> Result<Vec<u128>, Error> {
+    let mut lines = input.lines().enumerate();
+    let mut sum = 0;
+    let mut pq = PriorityQueue::new();
+
+    while let Some((i, line)) = lines.next() {
+        let last_input = line?;
+
+        if last_input.len() == 0 {
+            pq.push(sum, sum);
+            sum = 0;
+            continue;
+        }
+
+        let n = last_input
+            .parse::<u128>()
+            .map_err(|e| ParseError::new(i + 1, 1, &last_input, e.to_string()))?;
+        sum += n;
+    }
+
+    pq.push(sum, sum);
+
+    Ok(pq
+        .into_sorted_iter()
+        .take(n)
+        .map(|(item, _)| item)
+        .collect())
+}
+
+pub fn solve(input: impl BufRead) -> Result<(u128, u128), Error> {
+    let top = top_totals(input, 3)?;
+    Ok((top[0], top.iter().sum()))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn sample_case() {
+        let case = "1000
+2000
+3000
+
+4000
+
+5000
+6000
+
+7000
+8000
+9000
+
+10000";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, (24000, 45000));
+    }
+}
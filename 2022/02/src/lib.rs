@@ -0,0 +1,177 @@
+#![feature(is_some_and)]
+
+use aoc_common::{Answers, Error, ParseError, Solution};
+use std::io::{self, BufRead};
+
+#[derive(Copy, Clone)]
+enum Move {
+    ROCK = 1,
+    PAPER = 2,
+    SCISSORS = 3,
+}
+
+#[derive(Copy, Clone)]
+enum Result {
+    LOSE = 0,
+    DRAW = 3,
+    WIN = 6,
+}
+
+type ParseResult<T> = std::result::Result<T, ParseError>;
+
+struct Match {
+    //them: Move,
+    us: Move,
+    result: Result,
+}
+
+impl Match {
+    fn from_string_part_1(s: &str, line: usize) -> ParseResult<Self> {
+        let (them, us) = split_columns(s, line)?;
+        let them = convert_input_part_1(them)
+            .ok_or_else(|| ParseError::new(line, 1, them, "unknown move"))?;
+        let us = convert_input_part_1(us)
+            .ok_or_else(|| ParseError::new(line, s.len() - us.len() + 1, us, "unknown move"))?;
+        let result = match_result(them, us);
+        Ok(Match { us, result })
+    }
+
+    fn from_string_part_2(s: &str, line: usize) -> ParseResult<Self> {
+        let (them, result) = split_columns(s, line)?;
+        let them = convert_input_part_1(them)
+            .ok_or_else(|| ParseError::new(line, 1, them, "unknown move"))?;
+        let result = convert_input_part_2(result).ok_or_else(|| {
+            ParseError::new(line, s.len() - result.len() + 1, result, "unknown result")
+        })?;
+        let us = match_play(them, result);
+        Ok(Match { us, result })
+    }
+
+    fn score(self) -> i64 {
+        self.us as i64 + self.result as i64
+    }
+}
+
+fn split_columns(s: &str, line: usize) -> ParseResult<(&str, &str)> {
+    s.split_once(' ')
+        .ok_or_else(|| ParseError::new(line, 1, s, "expected two columns"))
+}
+
+fn convert_input_part_1(item: &str) -> Option<Move> {
+    match item {
+        "A" => Some(Move::ROCK),
+        "X" => Some(Move::ROCK),
+        "B" => Some(Move::PAPER),
+        "Y" => Some(Move::PAPER),
+        "C" => Some(Move::SCISSORS),
+        "Z" => Some(Move::SCISSORS),
+        _ => None,
+    }
+}
+
+fn convert_input_part_2(item: &str) -> Option<Result> {
+    match item {
+        "X" => Some(Result::LOSE),
+        "Y" => Some(Result::DRAW),
+        "Z" => Some(Result::WIN),
+        _ => None,
+    }
+}
+
+fn match_result(them: Move, us: Move) -> Result {
+    match them {
+        Move::ROCK => match us {
+            Move::ROCK => Result::DRAW,
+            Move::PAPER => Result::WIN,
+            Move::SCISSORS => Result::LOSE,
+        },
+        Move::PAPER => match us {
+            Move::ROCK => Result::LOSE,
+            Move::PAPER => Result::DRAW,
+            Move::SCISSORS => Result::WIN,
+        },
+        Move::SCISSORS => match us {
+            Move::ROCK => Result::WIN,
+            Move::PAPER => Result::LOSE,
+            Move::SCISSORS => Result::DRAW,
+        },
+    }
+}
+
+fn match_play(them: Move, result: Result) -> Move {
+    match them {
+        Move::ROCK => match result {
+            Result::DRAW => Move::ROCK,
+            Result::WIN => Move::PAPER,
+            Result::LOSE => Move::SCISSORS,
+        },
+        Move::PAPER => match result {
+            Result::LOSE => Move::ROCK,
+            Result::DRAW => Move::PAPER,
+            Result::WIN => Move::SCISSORS,
+        },
+        Move::SCISSORS => match result {
+            Result::WIN => Move::ROCK,
+            Result::LOSE => Move::PAPER,
+            Result::DRAW => Move::SCISSORS,
+        },
+    }
+}
+
+pub fn solve(input: impl BufRead) -> std::result::Result<(i64, i64), Error> {
+    let lines = input.lines();
+    let moves = lines
+        .map_while(|p| match p {
+            Ok(p) if p.is_empty() => None,
+            p => Some(p),
+        })
+        .collect::<io::Result<Vec<String>>>()?;
+    let score_p1 = moves
+        .iter()
+        .enumerate()
+        .map(|(i, p)| Match::from_string_part_1(p, i + 1))
+        .map(|m| m.map(Match::score))
+        .sum::<ParseResult<i64>>()?;
+    let score_p2 = moves
+        .iter()
+        .enumerate()
+        .map(|(i, p)| Match::from_string_part_2(p, i + 1))
+        .map(|m| m.map(Match::score))
+        .sum::<ParseResult<i64>>()?;
+
+    Ok((score_p1, score_p2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> std::result::Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "A Y
+B X
+C Z";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, (15, 12));
+    }
+
+    #[test]
+    fn unknown_move() {
+        let case = "A Y
+B W
+C Z";
+        match super::solve(case.as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(2, 3, "W", "unknown move")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
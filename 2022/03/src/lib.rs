@@ -0,0 +1,96 @@
+#![feature(exclusive_range_pattern)]
+#![feature(iter_array_chunks)]
+
+use aoc_common::{Answers, Error, ParseError, Solution};
+use std::collections::HashSet;
+use std::io::BufRead;
+use std::ops::BitAnd;
+
+fn convert_priority(val: &u8) -> Option<i32> {
+    match val {
+        65..91 => Some((val - 65 + 27).into()),
+        97..123 => Some((val - 96).into()),
+        _ => None,
+    }
+}
+
+fn convert_bag(line: usize, bag: &str) -> Result<Vec<i32>, ParseError> {
+    bag.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            u8::try_from(c)
+                .ok()
+                .and_then(|c| convert_priority(&c))
+                .ok_or_else(|| ParseError::new(line, i + 1, &c.to_string(), "unknown item"))
+        })
+        .collect()
+}
+
+pub fn solve(input: impl BufRead) -> Result<(i32, i32), Error> {
+    let lines = input.lines();
+    let bags = lines
+        .map_while(|p| match p {
+            Ok(p) if p.is_empty() => None,
+            p => Some(p),
+        })
+        .enumerate()
+        .map(|(i, p)| Ok(convert_bag(i + 1, &p?)?))
+        .collect::<Result<Vec<Vec<i32>>, Error>>()?;
+
+    let result_p1: i32 = bags
+        .iter()
+        .map(|x| x.split_at(x.len() / 2))
+        .map(|(x, y)| -> (HashSet<i32>, HashSet<i32>) {
+            (
+                HashSet::from_iter(x.to_vec()),
+                HashSet::from_iter(y.to_vec()),
+            )
+        })
+        .map(|(x, y)| x.bitand(&y).iter().sum::<i32>())
+        .sum();
+
+    let result_p2: i32 = bags
+        .iter()
+        .map(|x| -> HashSet<i32> { HashSet::from_iter(x.to_vec()) })
+        .array_chunks::<3>()
+        .map(|[x, y, z]| x.bitand(&y).bitand(&z).iter().sum::<i32>())
+        .sum();
+
+    Ok((result_p1, result_p2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL
+PmmdzqPrVvPwwTWBwg
+wMqvLMZHhHMvwLHjbvcjnnSBnvTQFn
+ttgJtRGJQctTZtZT
+CrZsJsPPZsGzwwsLwLmpwMDw";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, (157, 70));
+    }
+
+    #[test]
+    fn unknown_item() {
+        let case = "vJrwpWtwJgWrhcsFMMfFFhFp
+jqHRNqRjqz1GDLGLrsFMfFZSrLrFZsSL";
+        match super::solve(case.as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(2, 11, "1", "unknown item")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
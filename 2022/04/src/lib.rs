@@ -0,0 +1,106 @@
+extern crate gcollections;
+extern crate interval;
+
+use crate::interval::interval_set::*;
+use aoc_common::{Answers, Error, ParseError, Solution};
+use gcollections::ops::*;
+use itertools::Itertools;
+
+use std::io::BufRead;
+
+fn parse_range(line: usize, column: usize, s: &str) -> Result<(i64, i64), ParseError> {
+    let bad = || ParseError::new(line, column, s, "expected a range like 2-4");
+    let (a, b) = s.split_once('-').ok_or_else(bad)?;
+    Ok((a.parse().map_err(|_| bad())?, b.parse().map_err(|_| bad())?))
+}
+
+fn parse_assignments(line: usize, s: &str) -> Result<Vec<(i64, i64)>, ParseError> {
+    let (first, second) = s
+        .split_once(',')
+        .ok_or_else(|| ParseError::new(line, 1, s, "expected two ranges"))?;
+    Ok(vec![
+        parse_range(line, 1, first)?,
+        parse_range(line, first.len() + 2, second)?,
+    ])
+}
+
+pub fn solve(input: impl BufRead) -> Result<(i64, i64), Error> {
+    let lines = input.lines();
+    let sets = lines
+        .map_while(|p| match p {
+            Ok(p) if p.is_empty() => None,
+            p => Some(p),
+        })
+        .enumerate()
+        .map(|(i, p)| Ok(parse_assignments(i + 1, &p?)?))
+        .collect::<Result<Vec<Vec<(i64, i64)>>, Error>>()?;
+
+    let part1 = sets
+        .iter()
+        .map(|v| {
+            v.iter()
+                .map(|t| vec![*t].to_interval_set())
+                .collect_tuple()
+                .unwrap()
+        })
+        .map(|(s1, s2)| (s1.clone(), s2.clone(), s1.join(s2)))
+        .map(|(s1, s2, s)| if s == s1 || s == s2 { 1 } else { 0 })
+        .sum();
+
+    let part2 = sets
+        .iter()
+        .map(|v| {
+            v.iter()
+                .map(|t| vec![*t].to_interval_set())
+                .collect_tuple()
+                .unwrap()
+        })
+        .map(|(s1, s2)| {
+            if s1.join(s2).interval_count() == 1 {
+                1
+            } else {
+                0
+            }
+        })
+        .sum();
+
+    Ok((part1, part2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "2-4,6-8
+2-3,4-5
+5-7,7-9
+2-8,3-7
+6-6,4-6
+2-6,4-8";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, (2, 4));
+    }
+
+    #[test]
+    fn bad_range() {
+        let case = "2-4,6-8
+2-3,4_5";
+        match super::solve(case.as_bytes()) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e, ParseError::new(2, 5, "4_5", "expected a range like 2-4"))
+            }
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
@@ -0,0 +1,282 @@
+extern crate nom;
+use aoc_common::{parse_strict, Answers, Error, ParseError, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take_until},
+    character::complete::*,
+    combinator::{map_res, opt},
+    multi::{many1, separated_list1},
+    sequence::terminated,
+    IResult,
+};
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq)]
+pub struct Move {
+    pub many: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+fn crate_box(input: &str) -> IResult<&str, &str> {
+    let (input, _) = tag("[")(input)?;
+    let (input, c) = alpha1(input)?;
+    let (input, _) = tag("]")(input)?;
+    Ok((input, c))
+}
+
+fn empty_box(input: &str) -> IResult<&str, &str> {
+    let (input, _) = tag("   ")(input)?;
+    Ok((input, ""))
+}
+
+fn crate_or_empty(input: &str) -> IResult<&str, &str> {
+    alt((crate_box, empty_box))(input)
+}
+
+fn move_str(input: &str) -> IResult<&str, Move> {
+    let (input, _) = tag("move ")(input)?;
+    let (input, many) = map_res(digit1, usize::from_str)(input)?;
+    let (input, _) = tag(" from ")(input)?;
+    let (input, from) = map_res(digit1, usize::from_str)(input)?;
+    let (input, _) = tag(" to ")(input)?;
+    let (input, to) = map_res(digit1, usize::from_str)(input)?;
+    Ok((input, Move { many, from, to }))
+}
+
+fn all_input(input: &str) -> IResult<&str, (Vec<Vec<&str>>, Vec<Move>)> {
+    let (input, crates) = many1(terminated(
+        separated_list1(tag(" "), crate_or_empty),
+        newline,
+    ))(input)?;
+    let (input, _) = terminated(take_until("\n"), newline)(input)?;
+    let (input, _) = terminated(take_until("\n"), newline)(input)?;
+    let (input, moves) = terminated(separated_list1(newline, move_str), opt(newline))(input)?;
+    Ok((input, (crates, moves)))
+}
+
+/// Check that `m`, written on `line` (numbered `line_no`), can be carried out
+/// on stacks of the given `heights`, and apply it to them.
+fn check_move(
+    line_no: usize,
+    line: &str,
+    m: &Move,
+    heights: &mut [usize],
+) -> Result<(), ParseError> {
+    // The numbers are the second, fourth and sixth words of the line
+    let at = |word: usize, message: &str| {
+        let column = line
+            .split(' ')
+            .take(word)
+            .map(|w| w.len() + 1)
+            .sum::<usize>()
+            + 1;
+        let token = line.split(' ').nth(word).unwrap_or_default();
+        ParseError::new(line_no, column, token, message)
+    };
+    if !(1..=heights.len()).contains(&m.from) {
+        return Err(at(3, "no such stack"));
+    }
+    if !(1..=heights.len()).contains(&m.to) {
+        return Err(at(5, "no such stack"));
+    }
+    if heights[m.from - 1] < m.many {
+        return Err(at(1, "not enough crates on the stack"));
+    }
+    heights[m.from - 1] -= m.many;
+    heights[m.to - 1] += m.many;
+    Ok(())
+}
+
+pub fn solve(mut input: impl BufRead) -> Result<(String, String), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let (tower, moves) = parse_strict(&buffer, all_input)?;
+
+    // Trailing spaces may be missing from crate rows, so count the stacks on
+    // the line of stack numbers below them too
+    let numbers = buffer.lines().nth(tower.len()).unwrap_or_default();
+    let stacks = tower
+        .iter()
+        .map(Vec::len)
+        .chain([numbers.split_whitespace().count()])
+        .max()
+        .unwrap_or_default();
+    let mut ordered_tower = vec![vec![]; stacks];
+    for row in tower.iter().rev() {
+        for (j, l) in row.iter().enumerate() {
+            if *l != "" {
+                ordered_tower[j].push(*l);
+            }
+        }
+    }
+    let mut ordered_tower_p2 = ordered_tower.clone();
+
+    let mut heights = ordered_tower.iter().map(Vec::len).collect::<Vec<_>>();
+    let move_lines = buffer
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| l.starts_with("move "));
+    for (m, (i, line)) in moves.iter().zip(move_lines) {
+        check_move(i + 1, line, m, &mut heights)?;
+    }
+
+    for m in moves.iter() {
+        for _ in 0..m.many {
+            let val = ordered_tower[m.from - 1].pop().unwrap();
+            ordered_tower[m.to - 1].push(val);
+        }
+    }
+
+    for m in moves.iter() {
+        let mut tmp = vec![];
+        for _ in 0..m.many {
+            let val = ordered_tower_p2[m.from - 1].pop().unwrap();
+            tmp.push(val);
+        }
+
+        for _ in 0..m.many {
+            let val = tmp.pop().unwrap();
+            ordered_tower_p2[m.to - 1].push(val);
+        }
+    }
+
+    let part1 = ordered_tower
+        .iter()
+        .filter_map(|t| {
+            if t.len() > 0 {
+                Some(t[t.len() - 1])
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("");
+
+    let part2 = ordered_tower_p2
+        .iter()
+        .filter_map(|t| {
+            if t.len() > 0 {
+                Some(t[t.len() - 1])
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<&str>>()
+        .join("");
+
+    Ok((part1, part2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_crate_or_empty() {
+        assert_eq!(crate_or_empty("[X] "), Ok((" ", "X")));
+        assert_eq!(crate_or_empty("    "), Ok((" ", "")));
+    }
+
+    #[test]
+    fn parse_move_str() {
+        assert_eq!(
+            move_str("move 50 from 40 to 30"),
+            Ok((
+                "",
+                Move {
+                    many: 50,
+                    from: 40,
+                    to: 30
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn parse_sample() {
+        let sample = "    [D]    
+[N] [C]    
+[Z] [M] [P]
+    1   2   3 
+
+move 1 from 2 to 1
+move 3 from 1 to 3
+move 2 from 2 to 1
+move 1 from 1 to 2
+";
+        let expected_tower = vec![vec!["", "D", ""], vec!["N", "C", ""], vec!["Z", "M", "P"]];
+        let expected_moves = vec![
+            Move {
+                many: 1,
+                from: 2,
+                to: 1,
+            },
+            Move {
+                many: 3,
+                from: 1,
+                to: 3,
+            },
+            Move {
+                many: 2,
+                from: 2,
+                to: 1,
+            },
+            Move {
+                many: 1,
+                from: 1,
+                to: 2,
+            },
+        ];
+        assert_eq!(
+            all_input(sample),
+            Ok(("", (expected_tower, expected_moves)))
+        );
+
+        let result = super::solve(sample.as_bytes()).unwrap();
+        assert_eq!(result, ("CMZ".to_string(), "MCD".to_string()));
+    }
+
+    #[test]
+    fn trimmed_rows() {
+        let sample = "    [D]\n[N] [C]\n[Z] [M]\n 1   2   3\n\nmove 2 from 2 to 3\n";
+        let result = super::solve(sample.as_bytes()).unwrap();
+        assert_eq!(result, ("NMC".to_string(), "NMD".to_string()));
+    }
+
+    #[test]
+    fn reject_impossible_moves() {
+        let tower = "[A]    \n[B] [C]\n 1   2 \n\n";
+        let error = |moves: &str| match super::solve(format!("{tower}{moves}").as_bytes()) {
+            Err(Error::Parse(e)) => (e.line, e.column, e.token, e.message),
+            other => panic!("expected a parse error, got {other:?}"),
+        };
+        assert_eq!(
+            error("move 1 from 0 to 1\n"),
+            (5, 13, "0".to_string(), "no such stack".to_string())
+        );
+        assert_eq!(
+            error("move 1 from 1 to 3\n"),
+            (5, 18, "3".to_string(), "no such stack".to_string())
+        );
+        assert_eq!(
+            error("move 1 from 2 to 1\nmove 1 from 2 to 1\n"),
+            (
+                6,
+                6,
+                "1".to_string(),
+                "not enough crates on the stack".to_string()
+            )
+        );
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{Answers, Error, Solution};
+use std::collections::HashSet;
+use std::io::BufRead;
+
+/// Characters read up to the end of the first `len` distinct characters in a
+/// row.
+fn look_contiguous(buffer: &str, len: usize) -> Result<usize, Error> {
+    buffer
+        .trim_end()
+        .as_bytes()
+        .windows(len)
+        .position(|w| HashSet::<&u8>::from_iter(w).len() == len)
+        .map(|i| i + len)
+        .ok_or_else(|| Error::Unsolvable(format!("no {len} distinct characters in a row")))
+}
+
+pub fn solve(mut input: impl BufRead) -> Result<(usize, usize), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+
+    let sop = look_contiguous(&buffer, 4)?;
+    let som = look_contiguous(&buffer, 14)?;
+
+    Ok((sop, som))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_solve() {
+        assert_eq!(
+            solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb".as_bytes()).unwrap(),
+            (7, 19)
+        );
+        assert_eq!(
+            solve("bvwbjplbgvbhsrlpgdmjqwftvncz".as_bytes()).unwrap(),
+            (5, 23)
+        );
+        assert_eq!(
+            solve("nppdvjthqldpwncqszvftbrmjlhg".as_bytes()).unwrap(),
+            (6, 23)
+        );
+        assert_eq!(
+            solve("nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg".as_bytes()).unwrap(),
+            (10, 29)
+        );
+        assert_eq!(
+            solve("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw".as_bytes()).unwrap(),
+            (11, 26)
+        );
+    }
+
+    #[test]
+    fn test_no_marker() {
+        for input in ["", "abc", "abcabcabcabcabcabc\n"] {
+            match solve(input.as_bytes()) {
+                Err(Error::Unsolvable(e)) => assert!(e.starts_with("no ")),
+                r => panic!("unexpected {r:?}"),
+            }
+        }
+        assert_eq!(
+            solve("abcd\n".as_bytes()).unwrap_err().to_string(),
+            "cannot solve puzzle: no 14 distinct characters in a row"
+        );
+    }
+}
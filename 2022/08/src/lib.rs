@@ -0,0 +1,192 @@
+use aoc_common::{Answers, Error, ParseError, Solution};
+use std::cmp::{max, min};
+use std::io::BufRead;
+
+fn scenic_score(matrix: &Vec<Vec<i64>>, xp: usize, yp: usize) -> i64 {
+    let mut c1 = 0;
+    for x in (0..xp).rev() {
+        c1 += 1;
+        if matrix[x][yp] >= matrix[xp][yp] {
+            break;
+        }
+    }
+
+    let mut c2 = 0;
+    for x in xp + 1..matrix.len() {
+        c2 += 1;
+        if matrix[x][yp] >= matrix[xp][yp] {
+            break;
+        }
+    }
+
+    let mut c3 = 0;
+    for y in (0..yp).rev() {
+        c3 += 1;
+        if matrix[xp][y] >= matrix[xp][yp] {
+            break;
+        }
+    }
+
+    let mut c4 = 0;
+    for y in yp + 1..matrix[0].len() {
+        c4 += 1;
+        if matrix[xp][y] >= matrix[xp][yp] {
+            break;
+        }
+    }
+
+    c1 * c2 * c3 * c4
+}
+
+fn visible_map(matrix: &Vec<Vec<i64>>) -> Vec<Vec<bool>> {
+    let width = matrix[0].len();
+    let height = matrix.len();
+    let mut v: Vec<Vec<bool>> = std::iter::repeat(vec![false; width]).take(height).collect();
+    let mut hh: Vec<Vec<i64>> = std::iter::repeat(vec![0; width]).take(height).collect();
+    let mut hv: Vec<Vec<i64>> = std::iter::repeat(vec![0; width]).take(height).collect();
+
+    for i in 0..width {
+        v[0][i] = true;
+        hh[0][i] = matrix[0][i];
+        hv[0][i] = matrix[0][i];
+        v[height - 1][i] = true;
+        hh[height - 1][i] = matrix[height - 1][i];
+        hv[height - 1][i] = matrix[height - 1][i];
+    }
+    for i in 0..height {
+        v[i][0] = true;
+        hh[i][0] = matrix[i][0];
+        hv[i][0] = matrix[i][0];
+        v[i][width - 1] = true;
+        hh[i][width - 1] = matrix[i][width - 1];
+        hv[i][width - 1] = matrix[i][width - 1];
+    }
+
+    for x in 1..height - 1 {
+        for y in 1..width - 1 {
+            hh[x][y] = max(matrix[x][y], hh[x][y - 1]);
+            hv[x][y] = max(matrix[x][y], hv[x - 1][y]);
+            let minh = min(hv[x - 1][y], hh[x][y - 1]);
+            if minh >= matrix[x][y] {
+                v[x][y] = false;
+            } else {
+                v[x][y] = true;
+            }
+        }
+    }
+
+    for x in (1..height - 1).rev() {
+        for y in (1..width - 1).rev() {
+            hh[x][y] = max(matrix[x][y], hh[x][y + 1]);
+            hv[x][y] = max(matrix[x][y], hv[x + 1][y]);
+            let minh = min(hv[x + 1][y], hh[x][y + 1]);
+            if minh < matrix[x][y] {
+                v[x][y] = true;
+            }
+        }
+    }
+
+    v
+}
+
+fn parse_row(line: usize, row: &str) -> Result<Vec<i64>, ParseError> {
+    row.chars()
+        .enumerate()
+        .map(|(i, c)| {
+            c.to_digit(10)
+                .map(i64::from)
+                .ok_or_else(|| ParseError::new(line, i + 1, &c.to_string(), "expected a digit"))
+        })
+        .collect()
+}
+
+pub fn solve(input: impl BufRead) -> Result<(i64, i64), Error> {
+    let lines = input.lines();
+    let matrix = lines
+        .map_while(|p| match p {
+            Ok(p) if p.is_empty() => None,
+            p => Some(p),
+        })
+        .enumerate()
+        .map(|(i, p)| Ok(parse_row(i + 1, &p?)?))
+        .collect::<Result<Vec<Vec<i64>>, Error>>()?;
+    let width = matrix.first().map_or(0, Vec::len);
+    if width == 0 {
+        return Err(ParseError::new(1, 1, "", "no trees").into());
+    }
+    if let Some(i) = matrix.iter().position(|r| r.len() != width) {
+        let row = String::from_iter(matrix[i].iter().map(|h| h.to_string()));
+        return Err(ParseError::new(i + 1, 1, &row, "row length differs").into());
+    }
+
+    let v = visible_map(&matrix);
+
+    let part1 = v
+        .iter()
+        .map(|r| r.iter().map(|v| if *v { 1 } else { 0 }).sum::<i64>())
+        .sum();
+
+    let height = matrix.len();
+    let mut best_scenic = 0;
+    for x in 1..height {
+        for y in 1..width {
+            let score = scenic_score(&matrix, x, y);
+            best_scenic = max(best_scenic, score);
+        }
+    }
+
+    let part2 = best_scenic;
+
+    Ok((part1, part2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "30373
+25512
+65332
+33549
+35390
+";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, (21, 8));
+    }
+
+    #[test]
+    fn not_a_digit() {
+        let case = "30373
+255x2
+";
+        match super::solve(case.as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(2, 4, "x", "expected a digit")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn ragged_rows() {
+        match super::solve("30373\n255\n".as_bytes()) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e, ParseError::new(2, 1, "255", "row length differs"))
+            }
+            r => panic!("unexpected {r:?}"),
+        }
+        match super::solve("".as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(1, 1, "", "no trees")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
@@ -0,0 +1,229 @@
+use std::collections::HashMap;
+
+/// Index of the X register.
+pub const X: usize = 0;
+
+/// An instruction the CPU can execute.
+pub trait Instruction {
+    /// Name used to override the latency with [`Cpu::with_latency`].
+    fn mnemonic(&self) -> &str;
+
+    /// Number of cycles the instruction takes by default.
+    fn latency(&self) -> usize;
+
+    /// Apply the instruction once its last cycle has completed.
+    fn execute(&self, registers: &mut [isize]);
+}
+
+impl<I: Instruction + ?Sized> Instruction for Box<I> {
+    fn mnemonic(&self) -> &str {
+        (**self).mnemonic()
+    }
+
+    fn latency(&self) -> usize {
+        (**self).latency()
+    }
+
+    fn execute(&self, registers: &mut [isize]) {
+        (**self).execute(registers)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Breakpoint {
+    /// Stop before the given cycle (starting at 1) runs.
+    Cycle(usize),
+    /// Stop after an instruction changes the register to the value.
+    Register(usize, isize),
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Stop {
+    /// The program ran past its last instruction.
+    Halted,
+    Breakpoint(Breakpoint),
+}
+
+/// What hooks see: a cycle number and the registers during that cycle.
+#[derive(Debug)]
+pub struct Tick<'a> {
+    pub cycle: usize,
+    pub registers: &'a [isize],
+}
+
+pub struct Cpu {
+    registers: Vec<isize>,
+    latencies: HashMap<String, usize>,
+    breakpoints: Vec<Breakpoint>,
+    cycle: usize,
+    pc: usize,
+    busy: usize,
+    resumed: bool,
+}
+
+impl Cpu {
+    pub fn new(registers: Vec<isize>) -> Self {
+        Cpu {
+            registers,
+            latencies: HashMap::new(),
+            breakpoints: vec![],
+            cycle: 0,
+            pc: 0,
+            busy: 0,
+            resumed: false,
+        }
+    }
+
+    pub fn with_latency(mut self, mnemonic: &str, cycles: usize) -> Self {
+        self.latencies.insert(mnemonic.to_string(), cycles);
+        self
+    }
+
+    pub fn with_breakpoint(mut self, breakpoint: Breakpoint) -> Self {
+        self.breakpoints.push(breakpoint);
+        self
+    }
+
+    pub fn register(&self, r: usize) -> isize {
+        self.registers[r]
+    }
+
+    /// Number of cycles completed so far.
+    pub fn cycle(&self) -> usize {
+        self.cycle
+    }
+
+    fn latency(&self, op: &impl Instruction) -> usize {
+        let cycles = self.latencies.get(op.mnemonic()).copied();
+        cycles.unwrap_or_else(|| op.latency()).max(1)
+    }
+
+    /// Run `program` until it halts or hits a breakpoint, calling `hook` on
+    /// every cycle. Calling it again resumes where the last run stopped.
+    pub fn run<I: Instruction>(&mut self, program: &[I], mut hook: impl FnMut(&Tick)) -> Stop {
+        while let Some(op) = program.get(self.pc) {
+            let cycle = self.cycle + 1;
+            if !std::mem::take(&mut self.resumed) {
+                if let Some(bp) = self
+                    .breakpoints
+                    .iter()
+                    .find(|bp| **bp == Breakpoint::Cycle(cycle))
+                {
+                    self.resumed = true;
+                    return Stop::Breakpoint(*bp);
+                }
+            }
+
+            hook(&Tick {
+                cycle,
+                registers: &self.registers,
+            });
+            self.cycle = cycle;
+            self.busy += 1;
+
+            if self.busy >= self.latency(op) {
+                let before = self.registers.clone();
+                op.execute(&mut self.registers);
+                self.pc += 1;
+                self.busy = 0;
+
+                let hit = self.breakpoints.iter().find(|bp| match bp {
+                    Breakpoint::Register(r, v) => before[*r] != *v && self.registers[*r] == *v,
+                    Breakpoint::Cycle(_) => false,
+                });
+                if let Some(bp) = hit {
+                    return Stop::Breakpoint(*bp);
+                }
+            }
+        }
+
+        Stop::Halted
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    enum Asm {
+        Inc(usize),
+        Mul(usize, isize),
+    }
+
+    impl Instruction for Asm {
+        fn mnemonic(&self) -> &str {
+            match self {
+                Asm::Inc(_) => "inc",
+                Asm::Mul(..) => "mul",
+            }
+        }
+
+        fn latency(&self) -> usize {
+            match self {
+                Asm::Inc(_) => 1,
+                Asm::Mul(..) => 3,
+            }
+        }
+
+        fn execute(&self, registers: &mut [isize]) {
+            match self {
+                Asm::Inc(r) => registers[*r] += 1,
+                Asm::Mul(r, n) => registers[*r] *= n,
+            }
+        }
+    }
+
+    fn trace(cpu: &mut Cpu, program: &[Asm]) -> (Stop, Vec<(usize, isize, isize)>) {
+        let mut seen = vec![];
+        let stop = cpu.run(program, |t| {
+            seen.push((t.cycle, t.registers[0], t.registers[1]))
+        });
+        (stop, seen)
+    }
+
+    #[test]
+    fn test_latency() {
+        let program = [Asm::Inc(1), Asm::Mul(1, 5), Asm::Inc(0)];
+        let mut cpu = Cpu::new(vec![0, 1]);
+        let (stop, seen) = trace(&mut cpu, &program);
+        assert_eq!(stop, Stop::Halted);
+        assert_eq!(
+            seen,
+            vec![(1, 0, 1), (2, 0, 2), (3, 0, 2), (4, 0, 2), (5, 0, 10)]
+        );
+        assert_eq!((cpu.register(0), cpu.register(1)), (1, 10));
+
+        let mut cpu = Cpu::new(vec![0, 1]).with_latency("mul", 1);
+        trace(&mut cpu, &program);
+        assert_eq!(cpu.cycle(), 3);
+    }
+
+    #[test]
+    fn test_breakpoints() {
+        let program = [Asm::Inc(0), Asm::Inc(0), Asm::Mul(1, 2), Asm::Inc(0)];
+        let mut cpu = Cpu::new(vec![0, 3])
+            .with_breakpoint(Breakpoint::Cycle(3))
+            .with_breakpoint(Breakpoint::Register(1, 6));
+
+        let (stop, seen) = trace(&mut cpu, &program);
+        assert_eq!(stop, Stop::Breakpoint(Breakpoint::Cycle(3)));
+        assert_eq!(seen.len(), 2);
+
+        let (stop, seen) = trace(&mut cpu, &program);
+        assert_eq!(stop, Stop::Breakpoint(Breakpoint::Register(1, 6)));
+        assert_eq!(seen, vec![(3, 2, 3), (4, 2, 3), (5, 2, 3)]);
+
+        let (stop, seen) = trace(&mut cpu, &program);
+        assert_eq!(stop, Stop::Halted);
+        assert_eq!(seen, vec![(6, 2, 6)]);
+        assert_eq!(cpu.register(0), 3);
+    }
+
+    #[test]
+    fn test_boxed_instructions() {
+        let program: Vec<Box<dyn Instruction>> = vec![Box::new(Asm::Inc(0))];
+        let mut cpu = Cpu::new(vec![41]);
+        assert_eq!(cpu.run(&program, |_| ()), Stop::Halted);
+        assert_eq!(cpu.register(X), 42);
+    }
+}
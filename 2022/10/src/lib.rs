@@ -0,0 +1,495 @@
+extern crate nom;
+use aoc_common::{parse_strict, Answer, Answers, Error, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::*,
+    combinator::{map_res, opt},
+    multi::separated_list1,
+    IResult,
+};
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use std::str::FromStr;
+
+pub mod cpu;
+pub mod ocr;
+
+use cpu::{Cpu, Instruction, X};
+
+#[derive(Debug, PartialEq)]
+enum Op {
+    Addx(isize),
+    Noop,
+}
+
+fn parse_noop(input: &str) -> IResult<&str, Op> {
+    let (input, _) = tag("noop")(input)?;
+    Ok((input, Op::Noop))
+}
+
+fn parse_addx(input: &str) -> IResult<&str, Op> {
+    let (input, _) = tag("addx")(input)?;
+    let (input, _) = space1(input)?;
+    let (input, opt_sign) = opt(alt((char('+'), char('-'))))(input)?;
+    let sign = match opt_sign {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => 1,
+    };
+    let (input, many) = map_res(digit1, isize::from_str)(input)?;
+    Ok((input, Op::Addx(sign * many)))
+}
+
+fn parse_cmd(input: &str) -> IResult<&str, Op> {
+    alt((parse_noop, parse_addx))(input)
+}
+
+fn all_input(input: &str) -> IResult<&str, Vec<Op>> {
+    separated_list1(newline, parse_cmd)(input)
+}
+
+impl Instruction for Op {
+    fn mnemonic(&self) -> &str {
+        match self {
+            Op::Addx(_) => "addx",
+            Op::Noop => "noop",
+        }
+    }
+
+    fn latency(&self) -> usize {
+        match self {
+            Op::Addx(_) => 2,
+            Op::Noop => 1,
+        }
+    }
+
+    fn execute(&self, registers: &mut [isize]) {
+        if let Op::Addx(n) = self {
+            registers[X] += n;
+        }
+    }
+}
+
+/// Cycles sampled for the signal strength in the puzzle.
+pub const SAMPLES: &[usize] = &[20, 60, 100, 140, 180, 220];
+
+/// Size of the puzzle's CRT.
+pub const WIDTH: usize = 40;
+pub const HEIGHT: usize = 6;
+
+/// Size of the CRT and the cycles sampled for the signal strength.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Config {
+    width: usize,
+    height: usize,
+    samples: Vec<usize>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            width: WIDTH,
+            height: HEIGHT,
+            samples: SAMPLES.to_vec(),
+        }
+    }
+}
+
+impl Config {
+    /// Fails if `width` is zero or one of `samples` is zero, since cycles
+    /// count from 1.
+    pub fn new(width: usize, height: usize, samples: &[usize]) -> Result<Self, Error> {
+        if width == 0 {
+            return Err(Error::Unsolvable("CRT width must not be zero".to_string()));
+        }
+        if samples.contains(&0) {
+            return Err(Error::Unsolvable("cycles to sample start at 1".to_string()));
+        }
+        Ok(Config {
+            width,
+            height,
+            samples: samples.to_vec(),
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn samples(&self) -> &[usize] {
+        &self.samples
+    }
+}
+
+/// Value of X during every cycle of a program, followed by its final value.
+pub struct State {
+    s: Vec<isize>,
+}
+
+impl State {
+    /// Value of X during cycle `t + 1`. Once the program has halted X keeps
+    /// its final value.
+    pub fn get(&self, t: usize) -> isize {
+        self.s.get(t).copied().unwrap_or(self.s[self.s.len() - 1])
+    }
+}
+
+fn execute_cmds(cmds: Vec<Op>) -> State {
+    let mut cpu = Cpu::new(vec![1]);
+    let mut s = vec![];
+    cpu.run(&cmds, |tick| s.push(tick.registers[X]));
+    s.push(cpu.register(X));
+
+    State { s }
+}
+
+/// Parse and run a program.
+pub fn run(input: &str) -> Result<State, Error> {
+    let cmds = parse_strict(input, all_input)?;
+    Ok(execute_cmds(cmds))
+}
+
+/// Sum of the cycle number times X during each of the cycles sampled by
+/// `config`.
+pub fn signal_strength(s: &State, config: &Config) -> isize {
+    config
+        .samples
+        .iter()
+        .map(|&p| p as isize * s.get(p - 1))
+        .sum()
+}
+
+fn sprite_visible(s: &State, pixel: usize, width: usize) -> bool {
+    let column = (pixel % width) as isize;
+    (s.get(pixel) - column).abs() <= 1
+}
+
+/// Pixels drawn by a CRT of the given size, one per cycle.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Screen {
+    width: usize,
+    pixels: Vec<bool>,
+}
+
+impl Screen {
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.pixels.len() / self.width
+    }
+
+    pub fn lit(&self, x: usize, y: usize) -> bool {
+        self.pixels[y * self.width + x]
+    }
+}
+
+impl Display for Screen {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (y, row) in self.pixels.chunks(self.width).enumerate() {
+            if y > 0 {
+                writeln!(f)?;
+            }
+            for &p in row {
+                write!(f, "{}", if p { '#' } else { '.' })?;
+            }
+        }
+        Ok(())
+    }
+}
+
+pub fn render_crt(s: &State, config: &Config) -> Screen {
+    let (width, height) = (config.width, config.height);
+    let pixels = (0..width * height)
+        .map(|pixel| sprite_visible(s, pixel, width))
+        .collect();
+    Screen { width, pixels }
+}
+
+/// One line per cycle describing what the CRT draws, for debugging.
+pub fn trace<'a>(s: &'a State, config: &Config) -> impl Iterator<Item = String> + 'a {
+    let width = config.width;
+    (0..width * config.height).map(move |pixel| {
+        let c = if sprite_visible(s, pixel, width) {
+            '#'
+        } else {
+            '.'
+        };
+        format!(
+            "cycle {} sprite center {} -> {}",
+            pixel + 1,
+            s.get(pixel),
+            c
+        )
+    })
+}
+
+pub fn solve(mut input: impl BufRead) -> Result<(isize, Screen), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let s = run(&buffer)?;
+
+    let config = Config::default();
+    Ok((signal_strength(&s, &config), render_crt(&s, &config)))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, screen) = solve(input.as_bytes())?;
+        let screen = screen.to_string();
+        let p2 = match ocr::read(&screen) {
+            Some(letters) => Answer::Text(letters),
+            None => Answer::Glyphs(screen),
+        };
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseError;
+
+    #[test]
+    fn test_parse_cmd() {
+        assert_eq!(parse_cmd("noop"), Ok(("", Op::Noop)));
+        assert_eq!(parse_cmd("addx 5"), Ok(("", Op::Addx(5))));
+        assert_eq!(parse_cmd("addx -30"), Ok(("", Op::Addx(-30))));
+    }
+
+    #[test]
+    fn test_parse_error() {
+        match solve("addx five".as_bytes()) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e, ParseError::new(1, 6, "five", "unexpected input (Digit)"))
+            }
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    const SAMPLE: &str = "addx 15
+addx -11
+addx 6
+addx -3
+addx 5
+addx -1
+addx -8
+addx 13
+addx 4
+noop
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx 5
+addx -1
+addx -35
+addx 1
+addx 24
+addx -19
+addx 1
+addx 16
+addx -11
+noop
+noop
+addx 21
+addx -15
+noop
+noop
+addx -3
+addx 9
+addx 1
+addx -3
+addx 8
+addx 1
+addx 5
+noop
+noop
+noop
+noop
+noop
+addx -36
+noop
+addx 1
+addx 7
+noop
+noop
+noop
+addx 2
+addx 6
+noop
+noop
+noop
+noop
+noop
+addx 1
+noop
+noop
+addx 7
+addx 1
+noop
+addx -13
+addx 13
+addx 7
+noop
+addx 1
+addx -33
+noop
+noop
+noop
+addx 2
+noop
+noop
+noop
+addx 8
+noop
+addx -1
+addx 2
+addx 1
+noop
+addx 17
+addx -9
+addx 1
+addx 1
+addx -3
+addx 11
+noop
+noop
+addx 1
+noop
+addx 1
+noop
+noop
+addx -13
+addx -19
+addx 1
+addx 3
+addx 26
+addx -30
+addx 12
+addx -1
+addx 3
+addx 1
+noop
+noop
+noop
+addx -9
+addx 18
+addx 1
+addx 2
+noop
+noop
+addx 9
+noop
+noop
+noop
+addx -1
+addx 2
+addx -37
+addx 1
+addx 3
+noop
+addx 15
+addx -21
+addx 22
+addx -6
+addx 1
+noop
+addx 2
+addx 1
+noop
+addx -10
+noop
+noop
+addx 20
+addx 1
+addx 2
+addx 2
+addx -6
+addx -11
+noop
+noop
+noop";
+
+    #[test]
+    fn test_sample() {
+        let (strength, screen) = solve(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(strength, 13140);
+        assert_eq!(
+            screen.to_string(),
+            "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######....."
+        );
+    }
+
+    #[test]
+    fn test_signal_strength() {
+        let s = run(SAMPLE).unwrap();
+        let samples = |samples: &[usize]| Config::new(WIDTH, HEIGHT, samples).unwrap();
+        assert_eq!(signal_strength(&s, &samples(&[20])), 420);
+        assert_eq!(signal_strength(&s, &samples(&[220, 20])), 4380);
+        assert_eq!(signal_strength(&s, &samples(&[])), 0);
+    }
+
+    #[test]
+    fn test_render_crt() {
+        let s = run(SAMPLE).unwrap();
+        let screen = render_crt(&s, &Config::new(20, 2, SAMPLES).unwrap());
+        assert_eq!((screen.width(), screen.height()), (20, 2));
+        assert!(screen.lit(0, 0) && !screen.lit(2, 0));
+        assert_eq!(
+            screen.to_string(),
+            "##..##..##..##..##..\n...................."
+        );
+
+        let s = run("noop").unwrap();
+        let config = Config::new(5, 1, SAMPLES).unwrap();
+        assert_eq!(render_crt(&s, &config).to_string(), "###..");
+    }
+
+    #[test]
+    fn test_trace() {
+        let s = run("addx 3\nnoop").unwrap();
+        assert_eq!(
+            trace(&s, &Config::new(4, 1, SAMPLES).unwrap()).collect::<Vec<_>>(),
+            vec![
+                "cycle 1 sprite center 1 -> #",
+                "cycle 2 sprite center 1 -> #",
+                "cycle 3 sprite center 4 -> .",
+                "cycle 4 sprite center 4 -> #",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bad_config() {
+        let error = |config: Result<Config, Error>| match config {
+            Err(Error::Unsolvable(e)) => e,
+            r => panic!("unexpected {r:?}"),
+        };
+        assert_eq!(
+            error(Config::new(0, 6, SAMPLES)),
+            "CRT width must not be zero"
+        );
+        assert_eq!(
+            error(Config::new(40, 6, &[20, 0])),
+            "cycles to sample start at 1"
+        );
+        assert!(Config::new(40, 0, &[]).is_ok());
+    }
+}
@@ -0,0 +1,79 @@
+/// Width and height of a glyph in the AoC font, not counting the blank
+/// column separating two letters.
+const WIDTH: usize = 4;
+const HEIGHT: usize = 6;
+
+const FONT: &[(char, [&str; HEIGHT])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+fn glyph(rows: &[&str], index: usize) -> Option<char> {
+    let start = index * (WIDTH + 1);
+    FONT.iter()
+        .find(|(_, bitmap)| {
+            rows.iter()
+                .zip(bitmap.iter())
+                .all(|(row, expected)| row.get(start..start + WIDTH) == Some(*expected))
+        })
+        .map(|(c, _)| *c)
+}
+
+/// Read the letters drawn on a screen, one row per line, using the standard
+/// AoC font. Returns `None` if any glyph is not recognised.
+pub fn read(screen: &str) -> Option<String> {
+    let rows = screen.lines().collect::<Vec<_>>();
+    if rows.len() != HEIGHT {
+        return None;
+    }
+
+    let letters = (rows[0].len() + 1) / (WIDTH + 1);
+    (0..letters).map(|i| glyph(&rows, i)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(text: &str) -> String {
+        (0..HEIGHT)
+            .map(|row| {
+                text.chars()
+                    .map(|c| FONT.iter().find(|(f, _)| *f == c).unwrap().1[row])
+                    .collect::<Vec<_>>()
+                    .join(".")
+                    + "."
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+
+    #[test]
+    fn test_read() {
+        assert_eq!(read(&render("EHZRJBLF")), Some("EHZRJBLF".to_string()));
+        assert_eq!(read(&render("ACGIKOPS")), Some("ACGIKOPS".to_string()));
+    }
+
+    #[test]
+    fn test_unknown_glyph() {
+        let screen = render("ABCE").replacen(".##.", "####", 1);
+        assert_eq!(read(&screen), None);
+        assert_eq!(read("#..#"), None);
+    }
+}
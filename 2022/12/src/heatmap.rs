@@ -0,0 +1,60 @@
+use aoc_common::grid::Grid;
+use std::io::{self, Write};
+
+/// Distances as comma-separated rows, leaving unreachable squares empty.
+pub fn write_csv(out: &mut impl Write, field: &Grid<Option<usize>>) -> io::Result<()> {
+    for row in field.rows() {
+        let cells = row
+            .iter()
+            .map(|d| d.map_or(String::new(), |d| d.to_string()))
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", cells.join(","))?;
+    }
+    Ok(())
+}
+
+/// Distances as a plain 8-bit PGM image. Unreachable squares are black and
+/// the others get brighter the further they are from `E`.
+pub fn write_pgm(out: &mut impl Write, field: &Grid<Option<usize>>) -> io::Result<()> {
+    let max = field
+        .iter()
+        .filter_map(|(_, d)| *d)
+        .max()
+        .unwrap_or(0)
+        .max(1);
+    writeln!(out, "P2\n{} {}\n255", field.width(), field.height())?;
+    for row in field.rows() {
+        let cells = row
+            .iter()
+            .map(|d| d.map_or(0, |d| 1 + d * 254 / max).to_string())
+            .collect::<Vec<_>>();
+        writeln!(out, "{}", cells.join(" "))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn field() -> Grid<Option<usize>> {
+        Grid::from_rows(vec![vec![Some(0), Some(1)], vec![None, Some(2)]]).unwrap()
+    }
+
+    #[test]
+    fn test_csv() {
+        let mut out = vec![];
+        write_csv(&mut out, &field()).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "0,1\n,2\n");
+    }
+
+    #[test]
+    fn test_pgm() {
+        let mut out = vec![];
+        write_pgm(&mut out, &field()).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "P2\n2 2\n255\n1 128\n0 255\n"
+        );
+    }
+}
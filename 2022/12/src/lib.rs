@@ -0,0 +1,245 @@
+use aoc_common::grid::{self, Connectivity, Edges, Grid, Pos};
+use aoc_common::{Answer, Answers, Error, ParseError, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+
+pub mod heatmap;
+
+/// How steep a single step may be, in elevation levels.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Config {
+    pub max_ascent: u8,
+    pub max_descent: u8,
+}
+
+impl Default for Config {
+    /// The puzzle's rule: at most one level up, any drop down.
+    fn default() -> Self {
+        Config {
+            max_ascent: 1,
+            max_descent: b'z' - b'a',
+        }
+    }
+}
+
+impl Config {
+    fn can_step(&self, from: u8, to: u8) -> bool {
+        to <= from.saturating_add(self.max_ascent) && from <= to.saturating_add(self.max_descent)
+    }
+}
+
+/// No route leads to `E`.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unreachable;
+
+impl Display for Unreachable {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "`E` cannot be reached")
+    }
+}
+
+impl std::error::Error for Unreachable {}
+
+/// Squares that can step to `pos`. The search runs backwards from the goal,
+/// so these are the successors.
+fn successors<'a>(m: &'a Grid<u8>, config: &'a Config, pos: Pos) -> impl Iterator<Item = Pos> + 'a {
+    m.neighbours(pos, Connectivity::Four, Edges::Bounded)
+        .filter(move |&n| config.can_step(m[n], m[pos]))
+}
+
+fn parse_row(line: usize, row: &str) -> Result<Vec<u8>, ParseError> {
+    row.chars()
+        .enumerate()
+        .map(|(i, c)| match u8::try_from(c) {
+            Ok(v @ (b'a'..=b'z' | b'S' | b'E')) => Ok(v),
+            _ => Err(ParseError::new(
+                line,
+                i + 1,
+                &c.to_string(),
+                "unknown elevation",
+            )),
+        })
+        .collect()
+}
+
+/// The puzzle input, with `S` and `E` replaced by their elevations.
+#[derive(Clone, Debug)]
+pub struct Heightmap {
+    pub map: Grid<u8>,
+    pub start: Pos,
+    pub goal: Pos,
+}
+
+/// A shortest route up to `E`, starting at its first square.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Hike {
+    pub route: Vec<Pos>,
+}
+
+impl Hike {
+    pub fn steps(&self) -> usize {
+        self.route.len() - 1
+    }
+}
+
+/// Hikes for part 1 and part 2.
+pub type Hikes = (Result<Hike, Unreachable>, Result<Hike, Unreachable>);
+
+impl Heightmap {
+    pub fn parse(input: impl BufRead) -> Result<Self, Error> {
+        let lines = input.lines();
+        let mapvec = lines
+            .map_while(|p| match p {
+                Ok(p) if p.is_empty() => None,
+                p => Some(p),
+            })
+            .enumerate()
+            .map(|(i, p)| Ok(parse_row(i + 1, &p?)?))
+            .collect::<Result<Vec<Vec<_>>, Error>>()?;
+        let height = mapvec.len();
+        let mut map = Grid::from_rows(mapvec).map_err(|e| {
+            let row = String::from_utf8_lossy(&e.cells);
+            ParseError::new(e.row + 1, 1, &row, "row length differs")
+        })?;
+
+        let missing = |c| ParseError::new(height + 1, 1, "", format!("missing `{c}`"));
+        let start = map.position(&b'S').ok_or_else(|| missing('S'))?;
+        let goal = map.position(&b'E').ok_or_else(|| missing('E'))?;
+        map[start] = b'a';
+        map[goal] = b'z';
+        Ok(Heightmap { map, start, goal })
+    }
+
+    /// Steps from every square to `E`, or `None` where it is out of reach.
+    pub fn distances(&self, config: &Config) -> Grid<Option<usize>> {
+        grid::distances(&self.map, [self.goal], |p| successors(&self.map, config, p))
+    }
+
+    /// Shortest hikes from `S` and from the best square at elevation `a`.
+    pub fn hikes(&self, config: &Config) -> Hikes {
+        let field = self.distances(config);
+        let best = self
+            .map
+            .iter()
+            .filter(|&(p, &v)| v == b'a' && field[p].is_some())
+            .min_by_key(|&(p, _)| field[p])
+            .map(|(p, _)| p);
+        (
+            self.hike(config, &field, self.start),
+            best.map_or(Err(Unreachable), |p| self.hike(config, &field, p)),
+        )
+    }
+
+    /// Follow `field` down to `E`, one step closer each time.
+    fn hike(
+        &self,
+        config: &Config,
+        field: &Grid<Option<usize>>,
+        from: Pos,
+    ) -> Result<Hike, Unreachable> {
+        let map = &self.map;
+        let mut route = vec![from];
+        let mut pos = from;
+        while pos != self.goal {
+            let closer = field[pos].ok_or(Unreachable)?.checked_sub(1);
+            pos = map
+                .neighbours(pos, Connectivity::Four, Edges::Bounded)
+                .find(|&n| field[n] == closer && config.can_step(map[pos], map[n]))
+                .expect("distances decrease towards `E`");
+            route.push(pos);
+        }
+        Ok(Hike { route })
+    }
+
+    /// The heightmap with `route` drawn over it as arrows, as in the puzzle
+    /// statement.
+    pub fn render(&self, route: &[Pos]) -> String {
+        let mut rows = self
+            .map
+            .rows()
+            .map(|r| r.iter().map(|&v| v as char).collect::<Vec<_>>())
+            .collect::<Vec<_>>();
+        for step in route.windows(2) {
+            let ((x, y), (n_x, n_y)) = (step[0], step[1]);
+            rows[x][y] = match () {
+                _ if n_x < x => '^',
+                _ if n_x > x => 'v',
+                _ if n_y < y => '<',
+                _ => '>',
+            };
+        }
+        if let Some(&(x, y)) = route.last() {
+            rows[x][y] = 'E';
+        }
+        let rows = rows.into_iter().map(String::from_iter).collect::<Vec<_>>();
+        rows.join("\n")
+    }
+}
+
+pub fn solve(input: impl BufRead, config: &Config) -> Result<Hikes, Error> {
+    Ok(Heightmap::parse(input)?.hikes(config))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes(), &Config::default())?;
+        let answer = |hike: Result<Hike, Unreachable>| {
+            hike.map_or(Answer::NotApplicable, |h| h.steps().into())
+        };
+        Ok(Answers::new(answer(p1), answer(p2)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi";
+        let (p1, p2) = solve(case.as_bytes(), &Config::default()).unwrap();
+        let (p1, p2) = (p1.unwrap(), p2.unwrap());
+        assert_eq!((p1.steps(), p2.steps()), (31, 29));
+        assert_eq!(p1.route[0], (0, 0));
+        assert_eq!(p1.route[31], (2, 5));
+    }
+
+    #[test]
+    fn test_render() {
+        let case = "Sbcdefghijklmn
+aEzyxwvutsrqpo";
+        let map = Heightmap::parse(case.as_bytes()).unwrap();
+        let (p1, p2) = map.hikes(&Config::default());
+        let (p1, p2) = (p1.unwrap(), p2.unwrap());
+        assert_eq!((p1.steps(), p2.steps()), (26, 26));
+        assert_eq!(
+            map.render(&p1.route),
+            ">>>>>>>>>>>>>v
+aE<<<<<<<<<<<<"
+        );
+    }
+
+    #[test]
+    fn test_climbing_rule() {
+        let map = Heightmap::parse("SzaE".as_bytes()).unwrap();
+        let (p1, p2) = map.hikes(&Config::default());
+        assert_eq!((p1, p2), (Err(Unreachable), Err(Unreachable)));
+
+        let mut config = Config {
+            max_ascent: 25,
+            ..Config::default()
+        };
+        let (p1, p2) = map.hikes(&config);
+        assert_eq!((p1.unwrap().steps(), p2.unwrap().steps()), (3, 1));
+
+        config.max_descent = 3;
+        let (p1, p2) = map.hikes(&config);
+        assert_eq!((p1, p2.unwrap().steps()), (Err(Unreachable), 1));
+    }
+}
@@ -1,88 +1,65 @@
-use pathfinding::prelude::dijkstra;
-use std::io::{self, BufRead};
+use aoc_2022_12::{heatmap, Config, Hike, Unreachable};
+use std::env;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::process;
 
-#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-struct Pos<'a>(usize, usize, &'a Vec<Vec<u8>>);
+const USAGE: &str = "usage: aoc-2022-12 [-v|--verbose] [--ascent <n>] [--descent <n>]
+       [--export <file.csv|file.pgm>]";
 
-impl<'a> Pos<'a> {
-    fn successors(&self) -> Vec<(Pos<'a>, usize)> {
-        let &Pos(x, y, m) = self;
-        let mut pos = vec![];
-        if x > 0 && m[x][y] <= m[x - 1][y] + 1 {
-            pos.push(Pos(x - 1, y, m));
-        }
-        if y > 0 && m[x][y] <= m[x][y - 1] + 1 {
-            pos.push(Pos(x, y - 1, m));
-        }
-        if x + 1 < m.len() && m[x][y] <= m[x + 1][y] + 1 {
-            pos.push(Pos(x + 1, y, m));
-        }
-        if y + 1 < m[0].len() && m[x][y] <= m[x][y + 1] + 1 {
-            pos.push(Pos(x, y + 1, m));
-        }
-        pos.into_iter().map(|p| (p, 1)).collect()
-    }
+fn fail(message: impl std::fmt::Display) -> ! {
+    eprintln!("{message}");
+    process::exit(1);
 }
 
-fn solve(input: impl BufRead) -> (usize, usize) {
-    let lines = input.lines();
-    let mut mapvec = lines
-        .map_while(|p| {
-            if p.as_ref().unwrap().is_empty() {
-                None
-            } else {
-                Some(p.unwrap())
-            }
-        })
-        .map(|s| s.chars().map(|c| c as u8).collect::<Vec<_>>())
-        .collect::<Vec<Vec<_>>>();
-
-    let tmp = vec![];
-    let mut start = Pos(0, 0, &tmp);
-    let mut goal = Pos(0, 0, &tmp);
-
-    for (x, row) in mapvec.iter_mut().enumerate() {
-        for (y, v) in row.iter_mut().enumerate() {
-            match v {
-                b'E' => {
-                    *v = b'z';
-                    goal.0 = x;
-                    goal.1 = y;
-                }
-                b'S' => {
-                    *v = b'a';
-                    start.0 = x;
-                    start.1 = y;
+fn main() {
+    let mut config = Config::default();
+    let mut verbose = false;
+    let mut export = None;
+    let mut args = env::args().skip(1);
+    while let Some(flag) = args.next() {
+        match flag.as_str() {
+            "-v" | "--verbose" => verbose = true,
+            "--ascent" | "--descent" => {
+                let value = args.next().and_then(|v| v.parse().ok());
+                let value = value.unwrap_or_else(|| fail(USAGE));
+                if flag == "--ascent" {
+                    config.max_ascent = value;
+                } else {
+                    config.max_descent = value;
                 }
-                _ => (),
             }
+            "--export" => export = Some(args.next().unwrap_or_else(|| fail(USAGE))),
+            _ => fail(USAGE),
         }
     }
 
-    start.2 = &mapvec;
-    goal.2 = &mapvec;
+    let map = aoc_2022_12::Heightmap::parse(io::stdin().lock()).unwrap_or_else(|e| fail(e));
+    let (p1, p2) = map.hikes(&config);
 
-    let p1 = dijkstra(&goal, |p| p.successors(), |p| *p == start);
-    let p2 = dijkstra(&goal, |p| p.successors(), |p| p.2[p.0][p.1] == b'a');
-
-    (p1.unwrap().1, p2.unwrap().1)
-}
-
-fn main() {
-    let (score_p1, score_p2) = solve(io::stdin().lock());
-    println!("Total score: {score_p1} / {score_p2}");
-}
+    if verbose {
+        for hike in [&p1, &p2].into_iter().flatten() {
+            eprintln!("{}\n", map.render(&hike.route));
+        }
+    }
 
-#[cfg(test)]
-mod tests {
-    #[test]
-    fn sample_case() {
-        let case = "Sabqponm
-abcryxxl
-accszExk
-acctuvwj
-abdefghi";
-        let result = super::solve(case.as_bytes());
-        assert_eq!(result, (31, 29));
+    if let Some(path) = export {
+        let field = map.distances(&config);
+        let written = File::create(&path).and_then(|f| {
+            let mut out = BufWriter::new(f);
+            if path.ends_with(".pgm") {
+                heatmap::write_pgm(&mut out, &field)?;
+            } else {
+                heatmap::write_csv(&mut out, &field)?;
+            }
+            out.flush()
+        });
+        written.unwrap_or_else(|e| fail(e));
     }
+
+    let steps = |hike: Result<Hike, Unreachable>| match hike {
+        Ok(h) => h.steps().to_string(),
+        Err(e) => e.to_string(),
+    };
+    println!("Total score: {} / {}", steps(p1), steps(p2));
 }
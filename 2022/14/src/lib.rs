@@ -0,0 +1,417 @@
+extern crate nom;
+use aoc_common::{parse_strict, Answers, Error, Solution};
+use nom::{
+    bytes::complete::tag,
+    character::complete::*,
+    combinator::{map_res, opt},
+    multi::separated_list1,
+    sequence::tuple,
+    IResult,
+};
+use std::collections::HashSet;
+use std::iter::zip;
+use std::str::FromStr;
+use std::{
+    cmp::{max, min},
+    io::BufRead,
+};
+
+/// A position as (row, column), rows growing downwards.
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+struct Point(isize, isize);
+
+fn parse_number(input: &str) -> IResult<&str, isize> {
+    let (input, sign) = opt(char('-'))(input)?;
+    let (input, nr) = map_res(digit1, isize::from_str)(input)?;
+    Ok((input, if sign.is_some() { -nr } else { nr }))
+}
+
+fn parse_pair(input: &str) -> IResult<&str, Point> {
+    let (input, (a, _, b)) = tuple((parse_number, tag(","), parse_number))(input)?;
+    Ok((input, Point(b, a)))
+}
+
+fn parse_list(input: &str) -> IResult<&str, Vec<Point>> {
+    separated_list1(tag(" -> "), parse_pair)(input)
+}
+
+fn all_input(input: &str) -> IResult<&str, Vec<Vec<Point>>> {
+    separated_list1(newline, parse_list)(input)
+}
+
+/// What lies below the lowest rock.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Nothing: sand falling past the lowest rock is lost.
+    Abyss,
+    /// An endless floor two rows below the lowest rock.
+    Floor,
+}
+
+/// Rock and resting sand, stored sparsely so paths can lie anywhere.
+#[derive(Clone, Debug)]
+pub struct Cave {
+    rock: HashSet<Point>,
+    sand: HashSet<Point>,
+    bottom: isize,
+    mode: Mode,
+}
+
+impl Cave {
+    fn new(paths: &[Vec<Point>], mode: Mode) -> Self {
+        let mut rock = HashSet::new();
+        for path in paths.iter() {
+            for (src, dst) in zip(path.iter(), path.iter().skip(1)) {
+                for r in min(src.0, dst.0)..=max(src.0, dst.0) {
+                    for c in min(src.1, dst.1)..=max(src.1, dst.1) {
+                        rock.insert(Point(r, c));
+                    }
+                }
+            }
+        }
+        let bottom = rock.iter().map(|p| p.0).max().unwrap_or(0);
+
+        Cave {
+            rock,
+            sand: HashSet::new(),
+            bottom,
+            mode,
+        }
+    }
+
+    fn is_free(&self, p: &Point) -> bool {
+        let on_floor = self.mode == Mode::Floor && p.0 >= self.bottom + 2;
+        !on_floor && !self.rock.contains(p) && !self.sand.contains(p)
+    }
+
+    fn is_lost(&self, p: &Point) -> bool {
+        self.mode == Mode::Abyss && p.0 >= self.bottom
+    }
+
+    /// Where a grain at `pos` moves next, if it can move.
+    fn next(&self, pos: &Point) -> Option<Point> {
+        let below = [0, -1, 1].map(|dc| Point(pos.0 + 1, pos.1 + dc));
+        below.into_iter().find(|p| self.is_free(p))
+    }
+
+    /// Let a grain fall from `pos` and come to rest, or return `None` if it
+    /// falls into the abyss.
+    fn drop_from(&mut self, pos: Point) -> Option<Point> {
+        if self.is_lost(&pos) {
+            return None;
+        }
+
+        match self.next(&pos) {
+            Some(next) => self.drop_from(next),
+            None => {
+                self.sand.insert(pos.clone());
+                Some(pos)
+            }
+        }
+    }
+}
+
+/// Where sand enters the cave in the puzzle, as (x, y).
+pub const SOURCE: (isize, isize) = (500, 0);
+
+pub fn solve(input: impl BufRead) -> Result<(usize, usize), Error> {
+    solve_with(input, Method::Resume)
+}
+
+pub fn solve_with(input: impl BufRead, method: Method) -> Result<(usize, usize), Error> {
+    let (p1, p2) = solve_sources(input, &[SOURCE], method)?;
+    Ok((p1.iter().sum(), p2.iter().sum()))
+}
+
+/// Grains that came to rest from each of `sources`, given as (x, y), when
+/// they take turns dropping one grain each. Without a floor, the first grain
+/// lost ends the simulation.
+pub fn solve_sources(
+    mut input: impl BufRead,
+    sources: &[(isize, isize)],
+    method: Method,
+) -> Result<(Vec<usize>, Vec<usize>), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let rocks = parse_strict(&buffer, all_input)?;
+    let sources = sources
+        .iter()
+        .map(|(x, y)| Point(*y, *x))
+        .collect::<Vec<_>>();
+
+    let mut cave = Cave::new(&rocks, Mode::Abyss);
+    let count_p1 = run_simulation(&mut cave, &sources, method);
+    // _print_map(&cave, &sources);
+
+    let mut cave = Cave::new(&rocks, Mode::Floor);
+    let count_p2 = run_simulation(&mut cave, &sources, method);
+    //_print_map(&cave, &sources);
+
+    Ok((count_p1, count_p2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+/// How each grain finds where it comes to rest.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Method {
+    /// Every grain falls all the way from the source.
+    Restart,
+    /// Every grain follows the path of the previous one, so it starts from
+    /// the last position on that path that is still open.
+    Resume,
+}
+
+/// What happened to a grain.
+enum Grain {
+    Rested,
+    Lost,
+    /// The source itself is covered, no grain could enter.
+    Blocked,
+}
+
+impl Cave {
+    fn drop_grain(&mut self, source: &Point) -> Grain {
+        if !self.is_free(source) {
+            return Grain::Blocked;
+        }
+        match self.drop_from(source.clone()) {
+            Some(_) => Grain::Rested,
+            None => Grain::Lost,
+        }
+    }
+
+    /// Drop a grain along `path`, the path of the previous grain from the
+    /// same source, keeping the part that is still open.
+    fn resume(&mut self, path: &mut Vec<Point>) -> Grain {
+        if let Some(i) = path.iter().position(|p| !self.is_free(p)) {
+            path.truncate(i);
+        }
+        while let Some(pos) = path.last() {
+            if self.is_lost(pos) {
+                return Grain::Lost;
+            }
+            match self.next(pos) {
+                Some(next) => path.push(next),
+                None => {
+                    self.sand.extend(path.pop());
+                    return Grain::Rested;
+                }
+            }
+        }
+        Grain::Blocked
+    }
+}
+
+/// Drop grains from each source in turn until one is lost or every source
+/// is blocked, returning how many came to rest from each.
+fn run_simulation(cave: &mut Cave, sources: &[Point], method: Method) -> Vec<usize> {
+    let mut counts = vec![0; sources.len()];
+    let mut paths = sources.iter().map(|s| vec![s.clone()]).collect::<Vec<_>>();
+    loop {
+        let mut rested = false;
+        for (i, source) in sources.iter().enumerate() {
+            let grain = match method {
+                Method::Restart => cave.drop_grain(source),
+                Method::Resume => cave.resume(&mut paths[i]),
+            };
+            match grain {
+                Grain::Rested => {
+                    counts[i] += 1;
+                    rested = true;
+                }
+                Grain::Lost => return counts,
+                Grain::Blocked => (),
+            }
+        }
+        if !rested {
+            return counts;
+        }
+    }
+}
+
+/// Count the cells sand can reach from `source` in a cave with a floor.
+/// A cell is reachable when one of the three cells above it is, so the
+/// count is found one row at a time without simulating single grains.
+fn fill_count(cave: &Cave, source: &Point) -> usize {
+    assert_eq!(cave.mode, Mode::Floor, "only a floor bounds the fill");
+    let mut row = HashSet::new();
+    if cave.is_free(source) {
+        row.insert(source.1);
+    }
+
+    let mut count = 0;
+    let mut r = source.0;
+    while !row.is_empty() {
+        count += row.len();
+        r += 1;
+        row = row
+            .iter()
+            .flat_map(|c| [c - 1, *c, c + 1])
+            .filter(|c| cave.is_free(&Point(r, *c)))
+            .collect();
+    }
+    count
+}
+
+/// Part 2 by filling the reachable cells instead of simulating.
+pub fn solve_fill(mut input: impl BufRead) -> Result<usize, Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let rocks = parse_strict(&buffer, all_input)?;
+    let source = Point(SOURCE.1, SOURCE.0);
+    Ok(fill_count(&Cave::new(&rocks, Mode::Floor), &source))
+}
+
+fn _print_map(cave: &Cave, sources: &[Point]) {
+    let cells = cave.rock.iter().chain(cave.sand.iter()).chain(sources);
+    let (left, right) = cells.fold((isize::MAX, isize::MIN), |(l, r), p| {
+        (min(l, p.1), max(r, p.1))
+    });
+    let depth = match cave.mode {
+        Mode::Abyss => cave.bottom,
+        Mode::Floor => cave.bottom + 2,
+    };
+
+    for r in 0..=depth {
+        let line = (left..=right)
+            .map(|c| {
+                let p = Point(r, c);
+                if sources.contains(&p) {
+                    'S'
+                } else if cave.rock.contains(&p) || !cave.is_free(&p) {
+                    '#'
+                } else if cave.sand.contains(&p) {
+                    'o'
+                } else {
+                    '.'
+                }
+            })
+            .collect::<String>();
+        println!("{line}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    #[test]
+    fn test_parse_list() {
+        assert_eq!(
+            parse_list("498,4 -> 498,6 -> 496,6"),
+            Ok(("", vec![Point(4, 498), Point(6, 498), Point(6, 496)]))
+        );
+    }
+
+    const SAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(solve(SAMPLE.as_bytes()).unwrap(), (24, 93))
+    }
+
+    #[test]
+    fn test_methods_agree() {
+        let rocks = parse_strict(SAMPLE, all_input).unwrap();
+        for sources in [vec![Point(0, 500)], vec![Point(0, 500), Point(3, 497)]] {
+            for mode in [Mode::Abyss, Mode::Floor] {
+                let mut restart = Cave::new(&rocks, mode);
+                let mut resume = Cave::new(&rocks, mode);
+                assert_eq!(
+                    run_simulation(&mut restart, &sources, Method::Restart),
+                    run_simulation(&mut resume, &sources, Method::Resume)
+                );
+                assert_eq!(restart.sand, resume.sand);
+            }
+        }
+    }
+
+    #[test]
+    fn test_anywhere() {
+        // The sample moved far left of the source and far right of it, and
+        // with negative columns.
+        for shift in [-1000, -600, 3000] {
+            let sample = [
+                [(498, 4), (498, 6), (496, 6)].as_slice(),
+                &[(503, 4), (502, 4), (502, 9), (494, 9)],
+            ]
+            .map(|path| {
+                let points = path.iter().map(|(x, y)| format!("{},{y}", x + shift));
+                points.collect::<Vec<_>>().join(" -> ")
+            })
+            .join("\n");
+
+            // Nothing is under the source, so every grain is lost.
+            let (p1, p2) = solve(sample.as_bytes()).unwrap();
+            assert_eq!(p1, 0);
+            assert_eq!(p2, 11 * 11);
+        }
+    }
+
+    #[test]
+    fn test_wide_rock() {
+        let sample = "400,2 -> 600,2";
+        let (p1, p2) = solve(sample.as_bytes()).unwrap();
+        assert_eq!((p1, p2), (4, 4));
+    }
+
+    #[test]
+    fn test_sources() {
+        let shelf = "400,5 -> 700,5";
+        let apart = solve_sources(shelf.as_bytes(), &[(500, 0), (600, 0)], Method::Resume);
+        assert_eq!(apart.unwrap(), (vec![25, 25], vec![25, 25]));
+
+        let (p1, p2) =
+            solve_sources(shelf.as_bytes(), &[(500, 0), (502, 0)], Method::Resume).unwrap();
+        assert_eq!(p1, p2);
+        assert_eq!(p1.iter().sum::<usize>(), 2 + 5 + 7 + 9 + 11);
+
+        let (p1, p2) =
+            solve_sources(SAMPLE.as_bytes(), &[(500, 0), (480, 0)], Method::Resume).unwrap();
+        assert_eq!(p1, vec![1, 0]);
+        assert!(p2.iter().sum::<usize>() > 93);
+    }
+
+    #[test]
+    fn test_fill() {
+        assert_eq!(solve_fill(SAMPLE.as_bytes()).unwrap(), 93);
+    }
+
+    /// Rock paths around the source: a start and a few horizontal or
+    /// vertical moves.
+    fn paths() -> impl Strategy<Value = String> {
+        let moves = prop::collection::vec((any::<bool>(), -6isize..=6), 1..4);
+        let path = (485isize..515, 1isize..15, moves).prop_map(|(x, y, moves)| {
+            let mut points = vec![(x, y)];
+            for (horizontal, step) in moves {
+                let (x, y) = points[points.len() - 1];
+                points.push(if horizontal {
+                    (x + step, y)
+                } else {
+                    (x, (y + step).max(1))
+                });
+            }
+            let points = points.iter().map(|(x, y)| format!("{x},{y}"));
+            points.collect::<Vec<_>>().join(" -> ")
+        });
+        prop::collection::vec(path, 1..6).prop_map(|paths| paths.join("\n"))
+    }
+
+    proptest! {
+        #[test]
+        fn fill_matches_simulation(input in paths()) {
+            let (_, p2) = solve(input.as_bytes()).unwrap();
+            prop_assert_eq!(solve_fill(input.as_bytes()).unwrap(), p2);
+        }
+    }
+}
@@ -0,0 +1,608 @@
+extern crate nom;
+use aoc_common::{parse_strict, Answer, Answers, Error, Solution};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::*,
+    combinator::{map, map_res, opt},
+    multi::{many0, separated_list1},
+    sequence::{delimited, preceded, tuple},
+    IResult,
+};
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+use std::ops::{Range, RangeInclusive};
+use std::str::FromStr;
+
+#[derive(Debug, Eq, Hash, PartialEq, Clone)]
+pub struct Point(pub isize, pub isize);
+
+impl Point {
+    fn taxicab(&self, other: &Self) -> isize {
+        (self.0 - other.0).abs() + (self.1 - other.1).abs()
+    }
+}
+
+fn parse_number(input: &str) -> IResult<&str, isize> {
+    let (input, opt_sign) = opt(alt((char('+'), char('-'))))(input)?;
+    let sign = match opt_sign {
+        Some('+') => 1,
+        Some('-') => -1,
+        _ => 1,
+    };
+    let (input, nr) = map_res(digit1, isize::from_str)(input)?;
+    Ok((input, nr * sign))
+}
+
+fn parse_pair(input: &str) -> IResult<&str, (Point, Point)> {
+    let (input, (_, sx, _, sy, _, bx, _, by)) = tuple((
+        tag("Sensor at x="),
+        parse_number,
+        tag(", y="),
+        parse_number,
+        tag(": closest beacon is at x="),
+        parse_number,
+        tag(", y="),
+        parse_number,
+    ))(input)?;
+    Ok((input, (Point(sx, sy), Point(bx, by))))
+}
+
+/// A puzzle parameter given in the input header.
+#[derive(Clone, Debug, PartialEq)]
+enum Setting {
+    Row(isize),
+    X(RangeInclusive<isize>),
+    Y(RangeInclusive<isize>),
+    Tuning(isize),
+}
+
+fn parse_range(input: &str) -> IResult<&str, RangeInclusive<isize>> {
+    let (input, (start, _, end)) = tuple((parse_number, tag("..="), parse_number))(input)?;
+    Ok((input, start..=end))
+}
+
+fn parse_setting(input: &str) -> IResult<&str, Setting> {
+    alt((
+        map(preceded(tag("row="), parse_number), Setting::Row),
+        map(preceded(tag("x="), parse_range), Setting::X),
+        map(preceded(tag("y="), parse_range), Setting::Y),
+        map(preceded(tag("tuning="), parse_number), Setting::Tuning),
+    ))(input)
+}
+
+/// Lines such as `# row=10 x=0..=20 y=0..=20 tuning=4000000` before the
+/// sensors.
+fn parse_header(input: &str) -> IResult<&str, Vec<Setting>> {
+    let line = delimited(char('#'), many0(preceded(space1, parse_setting)), newline);
+    map(many0(line), |lines| lines.concat())(input)
+}
+
+/// Header settings and every sensor with its closest beacon.
+type Report = (Vec<Setting>, Vec<(Point, Point)>);
+
+fn all_input(input: &str) -> IResult<&str, Report> {
+    tuple((parse_header, separated_list1(newline, parse_pair)))(input)
+}
+
+/// How to look for the distress beacon.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum Search {
+    #[default]
+    Scan,
+    Boundaries,
+}
+
+/// Where to look for the distress beacon.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Region {
+    pub x: RangeInclusive<isize>,
+    pub y: RangeInclusive<isize>,
+}
+
+impl Region {
+    /// Coordinates from 0 to `max` on both axes.
+    pub fn square(max: isize) -> Self {
+        Region {
+            x: 0..=max,
+            y: 0..=max,
+        }
+    }
+
+    pub fn contains(&self, p: &Point) -> bool {
+        self.x.contains(&p.0) && self.y.contains(&p.1)
+    }
+}
+
+/// Puzzle parameters. Those left as `None` are taken from the input header,
+/// or else are the puzzle's own.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Config {
+    /// Row to count the positions without a beacon on.
+    pub row: Option<isize>,
+    /// Where to look for the distress beacon.
+    pub x: Option<RangeInclusive<isize>>,
+    pub y: Option<RangeInclusive<isize>>,
+    /// Multiplier of x in the tuning frequency.
+    pub tuning: Option<isize>,
+    pub search: Search,
+}
+
+impl Config {
+    fn apply(&mut self, setting: Setting) {
+        match setting {
+            Setting::Row(row) => self.row = Some(row),
+            Setting::X(x) => self.x = Some(x),
+            Setting::Y(y) => self.y = Some(y),
+            Setting::Tuning(tuning) => self.tuning = Some(tuning),
+        }
+    }
+
+    /// Fill in what this leaves open from `other`.
+    fn or(self, other: Config) -> Self {
+        Config {
+            row: self.row.or(other.row),
+            x: self.x.or(other.x),
+            y: self.y.or(other.y),
+            tuning: self.tuning.or(other.tuning),
+            search: self.search,
+        }
+    }
+
+    fn row(&self) -> isize {
+        self.row.unwrap_or(2000000)
+    }
+
+    fn region(&self) -> Region {
+        let square = Region::square(4000000);
+        Region {
+            x: self.x.clone().unwrap_or(square.x),
+            y: self.y.clone().unwrap_or(square.y),
+        }
+    }
+
+    fn tuning(&self) -> isize {
+        self.tuning.unwrap_or(4000000)
+    }
+}
+
+/// Sensors and the beacon closest to each.
+pub struct Sensors {
+    pairs: Vec<(Point, Point)>,
+}
+
+impl Sensors {
+    /// Parse the sensor report, skipping the optional header.
+    pub fn parse(input: &str) -> Result<Self, Error> {
+        let (_, pairs) = parse_strict(input, all_input)?;
+        Ok(Sensors { pairs })
+    }
+
+    /// Sorted, disjoint x ranges of row `y` within reach of some sensor.
+    fn reach_on_row(&self, y: isize) -> Vec<Range<isize>> {
+        let mut ranges = self
+            .pairs
+            .iter()
+            .filter_map(|(s, b)| {
+                let half = s.taxicab(b) - (s.1 - y).abs();
+                (half >= 0).then(|| s.0 - half..s.0 + half + 1)
+            })
+            .collect::<Vec<_>>();
+        ranges.sort_by_key(|r| r.start);
+
+        let mut merged: Vec<Range<isize>> = vec![];
+        for r in ranges {
+            match merged.last_mut() {
+                Some(last) if r.start <= last.end => last.end = last.end.max(r.end),
+                _ => merged.push(r),
+            }
+        }
+        merged
+    }
+
+    /// Sorted, disjoint x ranges of row `y` where there cannot be a beacon.
+    pub fn coverage_on_row(&self, y: isize) -> Vec<Range<isize>> {
+        let mut beacons = self
+            .pairs
+            .iter()
+            .filter(|(_, b)| b.1 == y)
+            .map(|(_, b)| b.0)
+            .collect::<Vec<_>>();
+        beacons.sort();
+        beacons.dedup();
+
+        let mut coverage = vec![];
+        for r in self.reach_on_row(y) {
+            let mut start = r.start;
+            for &x in beacons.iter().filter(|x| r.contains(x)) {
+                coverage.push(start..x);
+                start = x + 1;
+            }
+            coverage.push(start..r.end);
+        }
+        coverage.retain(|r| !r.is_empty());
+        coverage
+    }
+
+    /// Walk every column, skipping over the reach of each sensor.
+    fn scan(&self, region: &Region) -> Option<Point> {
+        for x in region.x.clone() {
+            let mut y = *region.y.start();
+            'inner: while y <= *region.y.end() {
+                let test = Point(x, y);
+                for (s, b) in self.pairs.iter() {
+                    let reach = b.taxicab(s) - (x - s.0).abs();
+                    if test.taxicab(s) <= b.taxicab(s) {
+                        y = s.1 + reach + 1;
+                        continue 'inner;
+                    }
+                }
+
+                return Some(test);
+            }
+        }
+        None
+    }
+
+    fn is_covered(&self, p: &Point) -> bool {
+        self.pairs.iter().any(|(s, b)| p.taxicab(s) <= b.taxicab(s))
+    }
+
+    /// Intersect the lines running just outside every sensor's reach. Along
+    /// the diagonals u = x + y and v = x - y a sensor's reach is a square,
+    /// so a lone uncovered point has to sit on one u and one v line, or
+    /// where such a line meets the edge of the region.
+    fn boundaries(&self, region: &Region) -> Option<Point> {
+        let (mut us, mut vs) = (vec![], vec![]);
+        for (s, b) in &self.pairs {
+            let r = s.taxicab(b) + 1;
+            us.extend([s.0 + s.1 - r, s.0 + s.1 + r]);
+            vs.extend([s.0 - s.1 - r, s.0 - s.1 + r]);
+        }
+
+        let xs = [*region.x.start(), *region.x.end()];
+        let ys = [*region.y.start(), *region.y.end()];
+        let crossings = us
+            .iter()
+            .flat_map(|u| vs.iter().map(move |v| (u, v)))
+            .filter(|(u, v)| (*u + *v) % 2 == 0)
+            .map(|(u, v)| Point((u + v) / 2, (u - v) / 2));
+        let on_edges = xs.into_iter().flat_map(|x| {
+            let on_u = us.iter().map(move |u| Point(x, u - x));
+            on_u.chain(vs.iter().map(move |v| Point(x, x - v)))
+        });
+        let on_rows = ys.into_iter().flat_map(|y| {
+            let on_u = us.iter().map(move |u| Point(u - y, y));
+            on_u.chain(vs.iter().map(move |v| Point(v + y, y)))
+        });
+        let corners = xs.into_iter().flat_map(|x| ys.map(|y| Point(x, y)));
+
+        crossings
+            .chain(on_edges)
+            .chain(on_rows)
+            .chain(corners)
+            .filter(|p| region.contains(p))
+            .find(|p| !self.is_covered(p))
+    }
+
+    /// A point in `region` no sensor can reach.
+    pub fn find_beacon(&self, region: &Region, search: Search) -> Option<Point> {
+        match search {
+            Search::Scan => self.scan(region),
+            Search::Boundaries => self.boundaries(region),
+        }
+    }
+
+    /// Every point in `region` no sensor can reach, row by row, failing once
+    /// there are more than `limit` of them.
+    pub fn uncovered(&self, region: &Region, limit: usize) -> Result<Vec<Point>, TooManyPoints> {
+        let mut points = vec![];
+        // Count each run first, it may be far too long to collect
+        let mut push = |xs: Range<isize>, y: isize| {
+            let len = (xs.end - xs.start).max(0) as usize;
+            if points.len() + len > limit {
+                return Err(TooManyPoints { limit });
+            }
+            points.extend(xs.map(|x| Point(x, y)));
+            Ok(())
+        };
+        for y in region.y.clone() {
+            let mut x = *region.x.start();
+            for r in self.reach_on_row(y) {
+                push(x..r.start.min(region.x.end() + 1), y)?;
+                x = x.max(r.end);
+            }
+            push(x..region.x.end() + 1, y)?;
+        }
+        Ok(points)
+    }
+
+    /// Draw `region` like the puzzle does: sensors as `S`, beacons as `B`
+    /// and positions within reach of a sensor as `#`. Meant for small
+    /// inputs, there is one character per position.
+    pub fn render(&self, region: &Region) -> String {
+        let mut out = String::new();
+        for y in region.y.clone() {
+            for x in region.x.clone() {
+                let p = Point(x, y);
+                out.push(if self.pairs.iter().any(|(s, _)| *s == p) {
+                    'S'
+                } else if self.pairs.iter().any(|(_, b)| *b == p) {
+                    'B'
+                } else if self.is_covered(&p) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+/// More uncovered points than the caller asked for.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TooManyPoints {
+    pub limit: usize,
+}
+
+impl Display for TooManyPoints {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "more than {} uncovered points", self.limit)
+    }
+}
+
+impl std::error::Error for TooManyPoints {}
+
+/// The distress beacon and its tuning frequency.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Beacon {
+    pub point: Point,
+    pub frequency: isize,
+}
+
+/// Count the positions on the target row that cannot hold a beacon and find
+/// the distress beacon. Settings in the input header apply where `config`
+/// leaves them open.
+pub fn solve(mut input: impl BufRead, config: &Config) -> Result<(usize, Option<Beacon>), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let (settings, pairs) = parse_strict(&buffer, all_input)?;
+    let sensors = Sensors { pairs };
+    let mut header = Config::default();
+    for setting in settings {
+        header.apply(setting);
+    }
+    let config = config.clone().or(header);
+
+    let impossible = sensors
+        .coverage_on_row(config.row())
+        .iter()
+        .map(|r| r.len())
+        .sum::<usize>();
+
+    let beacon = sensors
+        .find_beacon(&config.region(), config.search)
+        .map(|point| Beacon {
+            frequency: point.0 * config.tuning() + point.1,
+            point,
+        });
+
+    Ok((impossible, beacon))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, beacon) = solve(input.as_bytes(), &Config::default())?;
+        let p2 = beacon.map_or(Answer::NotApplicable, |b| b.frequency.into());
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::ParseError;
+
+    #[test]
+    fn test_parse_pair() {
+        assert_eq!(
+            parse_pair("Sensor at x=2, y=18: closest beacon is at x=-2, y=15"),
+            Ok(("", (Point(2, 18), Point(-2, 15))))
+        );
+    }
+
+    const SAMPLE: &str = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3";
+
+    #[test]
+    fn test_sample() {
+        let config = Config {
+            row: Some(10),
+            x: Some(0..=20),
+            y: Some(0..=20),
+            ..Config::default()
+        };
+        let beacon = Beacon {
+            point: Point(14, 11),
+            frequency: 56000011,
+        };
+        assert_eq!(
+            solve(SAMPLE.as_bytes(), &config).unwrap(),
+            (26, Some(beacon.clone()))
+        );
+
+        let header = "# row=10 x=0..=20\n# y=0..=20\n";
+        let input = format!("{header}{SAMPLE}");
+        assert_eq!(
+            solve(input.as_bytes(), &Config::default()).unwrap(),
+            (26, Some(beacon))
+        );
+    }
+
+    #[test]
+    fn test_header_and_config() {
+        let input = format!("# row=10 x=0..=20 y=0..=20 tuning=7\n{SAMPLE}");
+        let config = Config {
+            row: Some(11),
+            tuning: Some(1),
+            ..Config::default()
+        };
+        let row_11 = Config {
+            row: Some(11),
+            x: Some(0..=20),
+            y: Some(0..=20),
+            ..Config::default()
+        };
+        let (p1, beacon) = solve(input.as_bytes(), &config).unwrap();
+        assert_eq!(p1, solve(SAMPLE.as_bytes(), &row_11).unwrap().0);
+        assert_ne!(p1, 26);
+        assert_eq!(beacon.map(|b| b.frequency), Some(25));
+    }
+
+    #[test]
+    fn test_search() {
+        let sensors = Sensors::parse(SAMPLE).unwrap();
+        for search in [Search::Scan, Search::Boundaries] {
+            assert_eq!(
+                sensors.find_beacon(&Region::square(20), search),
+                Some(Point(14, 11))
+            );
+            assert_eq!(sensors.find_beacon(&Region::square(5), search), None);
+        }
+        assert_eq!(
+            solve(
+                SAMPLE.as_bytes(),
+                &Config {
+                    row: Some(10),
+                    x: Some(0..=20),
+                    y: Some(0..=20),
+                    tuning: Some(1),
+                    search: Search::Boundaries,
+                }
+            )
+            .unwrap(),
+            (
+                26,
+                Some(Beacon {
+                    point: Point(14, 11),
+                    frequency: 25
+                })
+            )
+        );
+    }
+
+    #[test]
+    fn test_search_corner() {
+        let sensors = Sensors::parse(
+            "Sensor at x=3, y=3: closest beacon is at x=3, y=8
+Sensor at x=6, y=6: closest beacon is at x=6, y=5
+Sensor at x=0, y=6: closest beacon is at x=0, y=5
+Sensor at x=6, y=0: closest beacon is at x=5, y=0",
+        )
+        .unwrap();
+        for search in [Search::Scan, Search::Boundaries] {
+            assert_eq!(
+                sensors.find_beacon(&Region::square(6), search),
+                Some(Point(0, 0))
+            );
+        }
+    }
+
+    #[test]
+    fn test_search_rectangle() {
+        let sensors = Sensors::parse(SAMPLE).unwrap();
+        let region = Region {
+            x: 14..=30,
+            y: -5..=11,
+        };
+        for search in [Search::Scan, Search::Boundaries] {
+            let found = sensors.find_beacon(&region, search).unwrap();
+            assert!(region.contains(&found) && !sensors.is_covered(&found));
+        }
+        let region = Region {
+            x: 0..=13,
+            y: 0..=20,
+        };
+        for search in [Search::Scan, Search::Boundaries] {
+            assert_eq!(sensors.find_beacon(&region, search), None);
+        }
+    }
+
+    #[test]
+    fn test_header_error() {
+        match solve("# row=x\n".as_bytes(), &Config::default()) {
+            Err(Error::Parse(e)) => {
+                assert_eq!(e, ParseError::new(1, 1, "#", "unexpected input (Tag)"))
+            }
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_coverage_on_row() {
+        let sensors = Sensors::parse(SAMPLE).unwrap();
+        assert_eq!(sensors.coverage_on_row(10), vec![-2..2, 3..25]);
+        assert_eq!(sensors.coverage_on_row(16), vec![-3..10, 11..27]);
+        assert_eq!(sensors.coverage_on_row(-10), vec![2..3]);
+        assert_eq!(sensors.coverage_on_row(100), vec![]);
+    }
+
+    #[test]
+    fn test_uncovered() {
+        let sensors = Sensors::parse(SAMPLE).unwrap();
+        let region = Region::square(20);
+        assert_eq!(sensors.uncovered(&region, 10), Ok(vec![Point(14, 11)]));
+
+        let region = Region {
+            x: -10..=-9,
+            y: 0..=1,
+        };
+        let points = sensors.uncovered(&region, 4).unwrap();
+        assert_eq!(
+            points,
+            vec![Point(-10, 0), Point(-9, 0), Point(-10, 1), Point(-9, 1)]
+        );
+        assert_eq!(
+            sensors.uncovered(&region, 3),
+            Err(TooManyPoints { limit: 3 })
+        );
+
+        let region = Region {
+            x: 0..=10_000_000_000,
+            y: 100..=100,
+        };
+        assert_eq!(
+            sensors.uncovered(&region, 10),
+            Err(TooManyPoints { limit: 10 })
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let sensors = Sensors::parse("Sensor at x=2, y=2: closest beacon is at x=3, y=3").unwrap();
+        assert_eq!(
+            sensors.render(&Region::square(4)),
+            "..#..\n.###.\n##S##\n.##B.\n..#..\n"
+        );
+        assert_eq!(
+            sensors.uncovered(&Region::square(4), 100).unwrap().len(),
+            12
+        );
+    }
+}
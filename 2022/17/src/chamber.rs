@@ -0,0 +1,194 @@
+/// Columns that fit in one row of the bit-packed store.
+pub const MAX_WIDTH: usize = 8;
+
+/// Deepest open space below the top of the tower, in rows, that
+/// [`Chamber::surface`] follows. Cells further down count as filled.
+pub const MAX_DEPTH: usize = 256;
+
+/// Settled rocks, one bit per cell and one `u8` per row. Rows are only
+/// stored up to the top of the tower, and everything below the open space a
+/// falling rock can still reach, or more than [`MAX_DEPTH`] rows below the
+/// top, is dropped.
+#[derive(Clone, Debug)]
+pub struct Chamber {
+    width: usize,
+    rows: Vec<u8>,
+    /// Number of rows discarded below `rows[0]`.
+    base: usize,
+}
+
+impl Chamber {
+    pub fn new(width: usize) -> Self {
+        assert!(
+            (1..=MAX_WIDTH).contains(&width),
+            "chamber width must be between 1 and {MAX_WIDTH}"
+        );
+        Chamber {
+            width,
+            rows: vec![],
+            base: 0,
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Height of the tower, including discarded rows.
+    pub fn height(&self) -> usize {
+        self.base + self.rows.len()
+    }
+
+    /// Rows still held in memory.
+    pub fn stored_rows(&self) -> usize {
+        self.rows.len()
+    }
+
+    fn full(&self) -> u8 {
+        (((1u16 << self.width) - 1) & 0xff) as u8
+    }
+
+    /// Bits of a row, or `None` if it was discarded. Rows above the tower
+    /// are empty.
+    pub fn row(&self, row: usize) -> Option<u8> {
+        let i = row.checked_sub(self.base)?;
+        Some(self.rows.get(i).copied().unwrap_or(0))
+    }
+
+    /// The top `n` stored rows, bottom first.
+    pub fn top(&self, n: usize) -> &[u8] {
+        &self.rows[self.rows.len().saturating_sub(n)..]
+    }
+
+    pub fn is_free(&self, row: isize, col: isize) -> bool {
+        if row < 0 || col < 0 || col >= self.width as isize {
+            return false;
+        }
+        match self.row(row as usize) {
+            Some(bits) => bits & (1 << col) == 0,
+            None => false,
+        }
+    }
+
+    /// Mark a cell as filled. The cell must be free.
+    pub fn fill(&mut self, row: usize, col: usize) {
+        let i = row - self.base;
+        if i >= self.rows.len() {
+            self.rows.resize(i + 1, 0);
+        }
+        self.rows[i] |= 1 << col;
+    }
+
+    /// The free cells a falling rock can still reach, as one row of bits per
+    /// row from the top of the tower down to the lowest such row. Rocks only
+    /// move down, left and right, so these are the free cells connected to
+    /// the open space above the tower by such moves, cut off after
+    /// `max_depth` rows.
+    pub fn surface(&self, max_depth: usize) -> Vec<u8> {
+        let full = self.full();
+        let mut surface = vec![];
+        let mut above = full;
+        for bits in self.rows.iter().rev().take(max_depth) {
+            let open = !bits & full;
+            let mut reach = above & open;
+            loop {
+                let spread = (reach | reach << 1 | reach >> 1) & open;
+                if spread == reach {
+                    break;
+                }
+                reach = spread;
+            }
+            if reach == 0 {
+                break;
+            }
+            surface.push(reach);
+            above = reach;
+        }
+        surface
+    }
+
+    /// Drop every row no falling rock can reach any more, and every row more
+    /// than [`MAX_DEPTH`] rows below the top.
+    pub fn seal(&mut self) {
+        let unreachable = self.rows.len() - self.surface(MAX_DEPTH).len();
+        self.rows.drain(..unreachable);
+        self.base += unreachable;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fill() {
+        let mut chamber = Chamber::new(3);
+        assert_eq!(chamber.height(), 0);
+        assert!(chamber.is_free(0, 0) && chamber.is_free(10, 2));
+        assert!(!chamber.is_free(-1, 0) && !chamber.is_free(0, 3));
+
+        chamber.fill(2, 1);
+        assert_eq!(chamber.height(), 3);
+        assert_eq!(chamber.top(5), &[0, 0, 0b010]);
+        assert!(!chamber.is_free(2, 1) && chamber.is_free(1, 1));
+    }
+
+    #[test]
+    fn test_surface() {
+        // |#..|
+        // |.##|  the cell on the left is covered
+        // |#.#|
+        let mut chamber = Chamber::new(3);
+        for (row, col) in [(0, 0), (0, 2), (1, 1), (1, 2), (2, 0)] {
+            chamber.fill(row, col);
+        }
+        assert_eq!(chamber.surface(10), vec![0b110]);
+        assert_eq!(chamber.surface(0), vec![]);
+
+        chamber.fill(3, 2);
+        assert_eq!(chamber.surface(10), vec![0b011, 0b110]);
+        assert_eq!(chamber.surface(1), vec![0b011]);
+    }
+
+    #[test]
+    fn test_seal() {
+        let mut chamber = Chamber::new(2);
+        chamber.fill(0, 0);
+        chamber.seal();
+        assert_eq!(chamber.stored_rows(), 1);
+
+        // An overhang the open space cannot get under
+        chamber.fill(1, 1);
+        chamber.seal();
+        assert_eq!((chamber.height(), chamber.stored_rows()), (2, 1));
+        assert_eq!(chamber.row(0), None);
+        assert!(!chamber.is_free(0, 1));
+        assert!(chamber.is_free(1, 0));
+
+        chamber.fill(1, 0);
+        chamber.seal();
+        assert_eq!((chamber.height(), chamber.stored_rows()), (2, 0));
+    }
+
+    #[test]
+    fn test_seal_open_column() {
+        // Rocks piled against the left wall, the right half open to the floor
+        let mut chamber = Chamber::new(8);
+        for row in 0..1000 {
+            for col in 0..4 {
+                chamber.fill(row, col);
+            }
+            chamber.seal();
+        }
+        assert_eq!(chamber.height(), 1000);
+        assert_eq!(chamber.stored_rows(), MAX_DEPTH);
+        assert!(!chamber.is_free(1000 - MAX_DEPTH as isize - 1, 7));
+        assert!(chamber.is_free(1000 - MAX_DEPTH as isize, 7));
+    }
+
+    #[test]
+    #[should_panic(expected = "chamber width")]
+    fn test_too_wide() {
+        Chamber::new(9);
+    }
+}
@@ -0,0 +1,477 @@
+use aoc_common::{Answers, Error, ParseError, Solution};
+use std::collections::HashMap;
+use std::ops::Add;
+
+pub mod chamber;
+pub mod shapes;
+pub mod visual;
+
+use chamber::Chamber;
+
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+struct Pos(isize, isize);
+
+impl Add for Pos {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self(self.0 + other.0, self.1 + other.1)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub struct Rock {
+    bits: Vec<Pos>,
+}
+
+impl Rock {
+    fn move_rock(&self, movement: &Pos, chamber: &Chamber) -> Option<Self> {
+        let new_rock = Rock {
+            bits: self.bits.iter().map(|x| *x + *movement).collect(),
+        };
+
+        if new_rock.bits.iter().all(|x| chamber.is_free(x.0, x.1)) {
+            Some(new_rock)
+        } else {
+            None
+        }
+    }
+}
+
+/// Parameters of the simulated chamber.
+#[derive(Clone, Debug)]
+pub struct Config {
+    pub width: usize,
+    /// Rocks in falling order, see [`shapes::parse_rocks`].
+    pub rocks: Vec<Rock>,
+    /// Rows between the top of the tower and the bottom of a new rock.
+    pub spawn_height: isize,
+    /// Columns between the left wall and the left edge of a new rock.
+    pub spawn_left: isize,
+    /// Jet characters and how many columns they push a rock to the right.
+    pub jets: Vec<(char, isize)>,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        Config {
+            width: 7,
+            rocks: shapes::parse_rocks(shapes::DEFAULT).unwrap(),
+            spawn_height: 3,
+            spawn_left: 2,
+            jets: vec![('<', -1), ('>', 1)],
+        }
+    }
+}
+
+/// Make sure every rock fits the chamber at its spawn point. The tower never
+/// reaches above the spawn height, so that is the only place to check.
+fn check(config: &Config) -> Result<(), Error> {
+    let unsolvable = |message: String| Err(Error::Unsolvable(message));
+    if !(1..=chamber::MAX_WIDTH).contains(&config.width) {
+        return unsolvable(format!(
+            "chamber width must be between 1 and {}",
+            chamber::MAX_WIDTH
+        ));
+    }
+    if config.rocks.is_empty() {
+        return unsolvable("no rocks".to_string());
+    }
+    if config.spawn_height < 0 {
+        return unsolvable("spawn height must not be negative".to_string());
+    }
+    let chamber = Chamber::new(config.width);
+    let spawn_point = Pos(config.spawn_height, config.spawn_left);
+    for (i, rock) in config.rocks.iter().enumerate() {
+        if rock.move_rock(&spawn_point, &chamber).is_none() {
+            return unsolvable(format!("rock {} does not fit the chamber", i + 1));
+        }
+    }
+    Ok(())
+}
+
+/// Rocks falling one at a time in a chamber.
+pub struct Tower<'a> {
+    config: &'a Config,
+    jets: Vec<Pos>,
+    chamber: Chamber,
+    rocks: usize,
+    jet: usize,
+    falling: Option<Rock>,
+}
+
+/// Everything that decides how the tower grows from here: the next rock, the
+/// next jet and the open cells a rock can still reach, counted from the top.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+struct Key {
+    rock: usize,
+    jet: usize,
+    surface: Vec<u8>,
+}
+
+impl<'a> Tower<'a> {
+    pub fn new(config: &'a Config, input: &str) -> Result<Self, Error> {
+        let jets = input
+            .chars()
+            .enumerate()
+            .map(
+                |(i, c)| match config.jets.iter().find(|(jet, _)| *jet == c) {
+                    Some((_, shift)) => Ok(Pos(0, *shift)),
+                    None => Err(ParseError::new(1, i + 1, &c.to_string(), "unknown jet")),
+                },
+            )
+            .collect::<Result<Vec<_>, _>>()?;
+        if jets.is_empty() {
+            return Err(ParseError::new(1, 1, "", "no jets").into());
+        }
+        check(config)?;
+
+        Ok(Tower {
+            config,
+            jets,
+            chamber: Chamber::new(config.width),
+            rocks: 0,
+            jet: 0,
+            falling: None,
+        })
+    }
+
+    pub fn height(&self) -> usize {
+        self.chamber.height()
+    }
+
+    /// Number of rocks that came to rest.
+    pub fn rocks(&self) -> usize {
+        self.rocks
+    }
+
+    pub fn chamber(&self) -> &Chamber {
+        &self.chamber
+    }
+
+    /// Cells of the rock currently falling, if any.
+    pub fn falling(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let bits = self.falling.iter().flat_map(|rock| &rock.bits);
+        bits.map(|Pos(x, y)| (*x as usize, *y as usize))
+    }
+
+    fn spawn(&self) -> Rock {
+        let spawn_point = Pos(
+            self.height() as isize + self.config.spawn_height,
+            self.config.spawn_left,
+        );
+        let r = &self.config.rocks[self.rocks % self.config.rocks.len()];
+        r.move_rock(&spawn_point, &self.chamber)
+            .expect("spawn point checked by Tower::new")
+    }
+
+    /// Push the falling rock with the next jet then let it fall one row,
+    /// spawning a new rock first if needed. Returns whether the rock came to
+    /// rest.
+    pub fn step(&mut self) -> bool {
+        let mut rock = self.falling.take().unwrap_or_else(|| self.spawn());
+
+        let m = &self.jets[self.jet];
+        self.jet = (self.jet + 1) % self.jets.len();
+        if let Some(nr) = rock.move_rock(m, &self.chamber) {
+            rock = nr
+        }
+        if let Some(nr) = rock.move_rock(&Pos(-1, 0), &self.chamber) {
+            self.falling = Some(nr);
+            return false;
+        }
+
+        for Pos(x, y) in &rock.bits {
+            self.chamber.fill(*x as usize, *y as usize);
+        }
+        self.chamber.seal();
+        self.rocks += 1;
+        true
+    }
+
+    /// Let the next rock fall until it comes to rest.
+    pub fn drop_rock(&mut self) {
+        while !self.step() {}
+    }
+
+    fn key(&self) -> Key {
+        Key {
+            rock: self.rocks % self.config.rocks.len(),
+            jet: self.jet,
+            surface: self.chamber.surface(chamber::MAX_DEPTH),
+        }
+    }
+}
+
+/// A repeating pattern in the growth of the tower: after the first `prefix`
+/// rocks, every `period` rocks add `height` rows.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct Cycle {
+    pub prefix: usize,
+    pub period: usize,
+    pub height: usize,
+}
+
+impl Cycle {
+    /// Height after `rocks` rocks, given the heights after each of the first
+    /// `prefix + period` rocks.
+    fn extrapolate(&self, heights: &[usize], rocks: usize) -> usize {
+        let (times, rest) = (
+            (rocks - self.prefix) / self.period,
+            (rocks - self.prefix) % self.period,
+        );
+        heights[self.prefix + rest] + times * self.height
+    }
+}
+
+/// Rocks [`simulate`] keeps track of while looking for a cycle. Past that it
+/// gives up and only keeps dropping rocks, so memory stays bounded.
+const HISTORY: usize = 100_000;
+
+/// Final height, and the cycle found with the height after each rock up to it.
+type Run = (usize, Option<(Cycle, Vec<usize>)>);
+
+/// Drop up to `limit` rocks, stopping early at a cycle that repeated twice
+/// with the same growth. Returns the height of the tower when it stopped and
+/// the cycle, if any, with the height after each rock up to there.
+fn simulate(config: &Config, input: &str, limit: usize) -> Result<Run, Error> {
+    let mut tower = Tower::new(config, input)?;
+    let mut heights = vec![0];
+    let mut seen: HashMap<Key, Vec<usize>> = HashMap::new();
+
+    while tower.rocks() < limit {
+        let n = tower.rocks();
+        if n == HISTORY {
+            seen = HashMap::new();
+            heights = vec![];
+        }
+
+        if n < HISTORY {
+            let earlier = seen.entry(tower.key()).or_default();
+            earlier.push(n);
+            if earlier.len() > 3 {
+                earlier.remove(0);
+            }
+
+            if let [first, second, _] = earlier[..] {
+                let period = n - second;
+                let h = &heights;
+                let growth =
+                    |start: usize| (0..period).map(move |i| h[start + i + 1] - h[start + i]);
+                if second - first == period && growth(first).eq(growth(second)) {
+                    let cycle = Cycle {
+                        prefix: first,
+                        period,
+                        height: heights[second] - heights[first],
+                    };
+                    return Ok((tower.height(), Some((cycle, heights))));
+                }
+            }
+        }
+
+        tower.drop_rock();
+        if n < HISTORY {
+            heights.push(tower.height());
+        }
+    }
+
+    Ok((tower.height(), None))
+}
+
+/// Look for a cycle in the growth of the tower within the first `limit`
+/// rocks, and at most the first [`HISTORY`] of them.
+pub fn find_cycle(config: &Config, input: &str, limit: usize) -> Result<Option<Cycle>, Error> {
+    Ok(simulate(config, input, limit)?.1.map(|(cycle, _)| cycle))
+}
+
+pub fn solve(input: String, take_len: usize) -> Result<isize, Error> {
+    solve_with(&Config::default(), &input, take_len)
+}
+
+/// Height of the tower after `take_len` rocks fell in the chamber described
+/// by `config`.
+pub fn solve_with(config: &Config, input: &str, take_len: usize) -> Result<isize, Error> {
+    let height = match simulate(config, input, take_len)? {
+        (_, Some((cycle, heights))) => cycle.extrapolate(&heights, take_len),
+        (height, None) => height,
+    };
+
+    Ok(height as isize)
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let jets = input.lines().next().unwrap_or_default();
+        let p1 = solve(jets.to_string(), 2022)?;
+        let p2 = solve(jets.to_string(), 1000000000000)?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case_1() {
+        let case = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let result = super::solve(case.to_string(), 2022).unwrap();
+        assert_eq!(result, 3068);
+    }
+
+    #[test]
+    fn sample_case_2() {
+        let case = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let result = super::solve(case.to_string(), 1000000000000).unwrap();
+        assert_eq!(result, 1514285714288);
+    }
+
+    #[test]
+    fn unknown_jet() {
+        match super::solve(">>><<>v<".to_string(), 2022) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(1, 7, "v", "unknown jet")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn no_jets() {
+        match super::solve(String::new(), 2022) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(1, 1, "", "no jets")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn bounded_memory() {
+        let config = Config::default();
+        let mut tower = Tower::new(&config, ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>").unwrap();
+        for _ in 0..20000 {
+            tower.drop_rock();
+        }
+        assert!(tower.height() > 30000);
+        assert!(tower.chamber().stored_rows() < 100);
+    }
+
+    #[test]
+    fn open_column_memory() {
+        // The right half of the chamber stays open down to the floor
+        let config = Config {
+            width: 8,
+            ..Config::default()
+        };
+        let mut tower = Tower::new(&config, "<").unwrap();
+        for _ in 0..20000 {
+            tower.drop_rock();
+        }
+        assert!(tower.chamber().stored_rows() <= chamber::MAX_DEPTH + 8);
+    }
+
+    #[test]
+    fn open_column_cycle() {
+        let big = 1_000_000_000_000;
+        assert_eq!(solve(">".to_string(), big).unwrap(), 2_600_000_000_000);
+        assert_eq!(solve("<".to_string(), big).unwrap(), 2_200_000_000_000);
+        let config = Config::default();
+        let mut tower = Tower::new(&config, "<").unwrap();
+        for _ in 0..1234 {
+            tower.drop_rock();
+        }
+        assert_eq!(
+            solve_with(&config, "<", 1234).unwrap(),
+            tower.height() as isize
+        );
+    }
+
+    #[test]
+    fn narrow_chamber() {
+        let config = Config {
+            width: 2,
+            rocks: shapes::parse_rocks("#\n\n##").unwrap(),
+            spawn_left: 0,
+            jets: vec![('<', -1), ('>', 1), ('=', 0)],
+            ..Config::default()
+        };
+        assert_eq!(solve_with(&config, "=", 4).unwrap(), 4);
+        assert_eq!(solve_with(&config, "><", 4).unwrap(), 4);
+    }
+
+    #[test]
+    fn rock_too_wide() {
+        let config = Config {
+            width: 3,
+            ..Config::default()
+        };
+        match solve_with(&config, ">", 1) {
+            Err(Error::Unsolvable(e)) => assert_eq!(e, "rock 1 does not fit the chamber"),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn bad_config() {
+        let error = |config: Config| match solve_with(&config, ">", 1) {
+            Err(Error::Unsolvable(e)) => e,
+            r => panic!("unexpected {r:?}"),
+        };
+        let width = |width| Config {
+            width,
+            ..Config::default()
+        };
+        assert_eq!(error(width(0)), "chamber width must be between 1 and 8");
+        assert_eq!(error(width(9)), "chamber width must be between 1 and 8");
+        let spawn = |spawn_height, spawn_left| Config {
+            spawn_height,
+            spawn_left,
+            ..Config::default()
+        };
+        assert_eq!(error(spawn(-5, 0)), "spawn height must not be negative");
+        assert_eq!(error(spawn(3, -1)), "rock 1 does not fit the chamber");
+        assert_eq!(error(spawn(3, 4)), "rock 1 does not fit the chamber");
+        let no_rocks = Config {
+            rocks: vec![],
+            ..Config::default()
+        };
+        assert_eq!(error(no_rocks), "no rocks");
+    }
+
+    #[test]
+    fn spawn_height() {
+        let jets = vec![('=', 0)];
+        for spawn_height in [0, 1, 5] {
+            let config = Config {
+                spawn_height,
+                jets: jets.clone(),
+                ..Config::default()
+            };
+            assert_eq!(solve_with(&config, "=", 3).unwrap(), 7);
+        }
+    }
+
+    #[test]
+    fn sample_cycle() {
+        let case = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let cycle = find_cycle(&Config::default(), case, 10000)
+            .unwrap()
+            .unwrap();
+        assert_eq!((cycle.period, cycle.height), (35, 53));
+        assert!(cycle.prefix < 100);
+        assert_eq!(find_cycle(&Config::default(), case, 20).unwrap(), None);
+    }
+
+    #[test]
+    fn cycle_matches_simulation() {
+        let case = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+        let config = Config::default();
+        let mut tower = Tower::new(&config, case).unwrap();
+        for _ in 0..5000 {
+            tower.drop_rock();
+        }
+        assert_eq!(
+            solve_with(&config, case, 5000).unwrap(),
+            tower.height() as isize
+        );
+    }
+}
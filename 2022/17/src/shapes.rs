@@ -0,0 +1,96 @@
+use crate::{Pos, Rock};
+use aoc_common::ParseError;
+
+/// The five rocks from the puzzle, in falling order.
+pub const DEFAULT: &str = include_str!("rocks.txt");
+
+fn parse_rock(first_line: usize, lines: &[&str]) -> Result<Rock, ParseError> {
+    let mut bits = vec![];
+    for (i, line) in lines.iter().enumerate() {
+        let row = (lines.len() - 1 - i) as isize;
+        for (col, c) in line.chars().enumerate() {
+            match c {
+                '#' => bits.push(Pos(row, col as isize)),
+                '.' => (),
+                _ => {
+                    let token = c.to_string();
+                    return Err(ParseError::new(
+                        first_line + i,
+                        col + 1,
+                        &token,
+                        "unknown tile",
+                    ));
+                }
+            }
+        }
+    }
+
+    let Some(bottom) = bits.iter().map(|p| p.0).min() else {
+        return Err(ParseError::new(first_line, 1, lines[0], "empty rock"));
+    };
+    let left = bits.iter().map(|p| p.1).min().unwrap_or_default();
+    let bits = bits.into_iter().map(|Pos(r, c)| Pos(r - bottom, c - left));
+    Ok(Rock {
+        bits: bits.collect(),
+    })
+}
+
+/// Parse rock shapes drawn with `#` and `.`, top row first, separated by
+/// blank lines. Shapes are shifted so their lowest, leftmost cell is at the
+/// origin.
+pub fn parse_rocks(input: &str) -> Result<Vec<Rock>, ParseError> {
+    let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+    let mut rocks = vec![];
+    let mut start = 0;
+    for (i, line) in lines.iter().chain([&""]).enumerate() {
+        if line.is_empty() {
+            if start < i {
+                rocks.push(parse_rock(start + 1, &lines[start..i])?);
+            }
+            start = i + 1;
+        }
+    }
+
+    if rocks.is_empty() {
+        return Err(ParseError::at(input, "", "no rocks"));
+    }
+    Ok(rocks)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default() {
+        let rocks = parse_rocks(DEFAULT).unwrap();
+        assert_eq!(rocks.len(), 5);
+        assert_eq!(
+            rocks[2].bits,
+            vec![Pos(2, 2), Pos(1, 2), Pos(0, 0), Pos(0, 1), Pos(0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_offset_shape() {
+        let rocks = parse_rocks("\n..\n.#\n\n\n#\n").unwrap();
+        assert_eq!(rocks.len(), 2);
+        assert_eq!(rocks[0].bits, vec![Pos(0, 0)]);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            parse_rocks("##\n\n#x\n").unwrap_err(),
+            ParseError::new(3, 2, "x", "unknown tile")
+        );
+        assert_eq!(
+            parse_rocks("#\n\n..\n").unwrap_err(),
+            ParseError::new(3, 1, "..", "empty rock")
+        );
+        assert_eq!(
+            parse_rocks("\n\n").unwrap_err(),
+            ParseError::new(3, 1, "", "no rocks")
+        );
+    }
+}
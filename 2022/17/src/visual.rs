@@ -0,0 +1,173 @@
+use crate::{Config, Tower};
+use aoc_common::Error;
+use std::io::{self, Write};
+
+/// How far the simulation advances between two frames.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Granularity {
+    /// One jet push and one row of fall.
+    Jet,
+    /// One rock coming to rest.
+    Rock,
+}
+
+/// Draw the top `rows` rows of the chamber the way the puzzle does, with the
+/// falling rock as `@` and the floor once it is in view. Rows the chamber
+/// dropped because no rock can reach them any more are drawn with `~`.
+pub fn render(tower: &Tower, rows: usize) -> String {
+    let width = tower.chamber().width();
+    let falling = tower.falling().collect::<Vec<_>>();
+    let top = falling
+        .iter()
+        .map(|(row, _)| row + 1)
+        .chain([tower.height()])
+        .max()
+        .unwrap_or_default();
+
+    let mut out = String::new();
+    for row in (top.saturating_sub(rows)..top).rev() {
+        out.push('|');
+        for col in 0..width {
+            out.push(if falling.contains(&(row, col)) {
+                '@'
+            } else if tower.chamber().row(row).is_none() {
+                '~'
+            } else if tower.chamber().is_free(row as isize, col as isize) {
+                '.'
+            } else {
+                '#'
+            });
+        }
+        out.push_str("|\n");
+    }
+    if top <= rows {
+        out.push_str(&format!("+{}+\n", "-".repeat(width)));
+    }
+    out
+}
+
+/// Frames of a simulation, advancing by `granularity` before each one.
+pub struct Frames<'a> {
+    tower: Tower<'a>,
+    granularity: Granularity,
+    rows: usize,
+}
+
+impl<'a> Frames<'a> {
+    pub fn new(
+        config: &'a Config,
+        input: &str,
+        granularity: Granularity,
+        rows: usize,
+    ) -> Result<Self, Error> {
+        Ok(Frames {
+            tower: Tower::new(config, input)?,
+            granularity,
+            rows,
+        })
+    }
+}
+
+impl Iterator for Frames<'_> {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        match self.granularity {
+            Granularity::Jet => {
+                self.tower.step();
+            }
+            Granularity::Rock => self.tower.drop_rock(),
+        }
+        Some(render(&self.tower, self.rows))
+    }
+}
+
+/// Write frames one after the other, separated by a blank line.
+pub fn write_text(out: &mut impl Write, frames: impl Iterator<Item = String>) -> io::Result<()> {
+    for frame in frames {
+        writeln!(out, "{frame}")?;
+    }
+    Ok(())
+}
+
+fn json_string(s: &str) -> String {
+    let mut out = String::from('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+/// Write frames as an asciicast v2 recording, `delay` seconds apart. Each
+/// frame clears the terminal before drawing.
+pub fn write_asciicast(
+    out: &mut impl Write,
+    frames: impl Iterator<Item = String>,
+    width: usize,
+    height: usize,
+    delay: f64,
+) -> io::Result<()> {
+    writeln!(
+        out,
+        "{{\"version\": 2, \"width\": {width}, \"height\": {height}}}"
+    )?;
+    for (i, frame) in frames.enumerate() {
+        let data = format!("\x1b[H\x1b[2J{}", frame.replace('\n', "\r\n"));
+        writeln!(
+            out,
+            "[{:.3}, \"o\", {}]",
+            i as f64 * delay,
+            json_string(&data)
+        )?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CASE: &str = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>";
+
+    #[test]
+    fn test_rock_frames() {
+        let config = Config::default();
+        let frames = Frames::new(&config, CASE, Granularity::Rock, 10).unwrap();
+        let frames = frames.take(2).collect::<Vec<_>>();
+        assert_eq!(frames[0], "|..####.|\n+-------+\n");
+        assert_eq!(
+            frames[1],
+            "|...#...|\n|..###..|\n|...#...|\n|..####.|\n+-------+\n"
+        );
+    }
+
+    #[test]
+    fn test_jet_frames() {
+        let config = Config::default();
+        let mut frames = Frames::new(&config, CASE, Granularity::Jet, 2).unwrap();
+        assert_eq!(frames.next().unwrap(), "|...@@@@|\n|.......|\n");
+        assert_eq!(frames.nth(2).unwrap(), "|..####.|\n+-------+\n");
+    }
+
+    #[test]
+    fn test_asciicast() {
+        let mut out = vec![];
+        let frames = ["|#|\n".to_string(), "|.|\n".to_string()];
+        write_asciicast(&mut out, frames.into_iter(), 3, 2, 0.5).unwrap();
+        assert_eq!(
+            String::from_utf8(out).unwrap(),
+            "{\"version\": 2, \"width\": 3, \"height\": 2}
+[0.000, \"o\", \"\\u001b[H\\u001b[2J|#|\\r\\n\"]
+[0.500, \"o\", \"\\u001b[H\\u001b[2J|.|\\r\\n\"]
+"
+        );
+    }
+}
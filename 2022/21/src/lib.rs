@@ -0,0 +1,308 @@
+extern crate nom;
+use aoc_common::{parse_strict, Answers, Error, ParseError, Solution};
+use nom::sequence::terminated;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    bytes::complete::{take, take_until},
+    character::complete::*,
+    combinator::map_res,
+    multi::separated_list1,
+    IResult,
+};
+use std::collections::HashMap;
+use std::io::BufRead;
+use std::str::FromStr;
+
+#[derive(Debug, PartialEq, Clone)]
+enum Operation {
+    Sum(String, String),
+    Sub(String, String),
+    Times(String, String),
+    Div(String, String),
+    Const(isize),
+}
+
+impl Operation {
+    /// The two monkeys waited on, unless this is a number.
+    fn operands(&self) -> Option<(&str, &str)> {
+        match self {
+            Operation::Sum(a, b)
+            | Operation::Sub(a, b)
+            | Operation::Times(a, b)
+            | Operation::Div(a, b) => Some((a, b)),
+            Operation::Const(_) => None,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+struct Monkey {
+    name: String,
+    operation: Operation,
+}
+
+fn parse_op(input: &str) -> IResult<&str, Operation> {
+    let (input, fst) = terminated(take_until(" "), tag(" "))(input)?;
+    let (input, sign) =
+        terminated(alt((char('*'), char('+'), char('-'), char('/'))), tag(" "))(input)?;
+    let (input, snd) = take(4usize)(input)?;
+
+    Ok((
+        input,
+        match sign {
+            '+' => Operation::Sum(fst.to_string(), snd.to_string()),
+            '-' => Operation::Sub(fst.to_string(), snd.to_string()),
+            '*' => Operation::Times(fst.to_string(), snd.to_string()),
+            '/' => Operation::Div(fst.to_string(), snd.to_string()),
+            _ => unreachable!(),
+        },
+    ))
+}
+
+fn parse_number(input: &str) -> IResult<&str, Operation> {
+    let (input, num) = map_res(digit1, isize::from_str)(input)?;
+    Ok((input, Operation::Const(num)))
+}
+
+fn parse_monkey(input: &str) -> IResult<&str, Monkey> {
+    let (input, name) = terminated(take_until(":"), tag(": "))(input)?;
+
+    let (input, op) = alt((parse_op, parse_number))(input)?;
+
+    Ok((
+        input,
+        Monkey {
+            name: name.to_string(),
+            operation: op,
+        },
+    ))
+}
+
+fn all_input(input: &str) -> IResult<&str, Vec<Monkey>> {
+    separated_list1(tag("\n"), parse_monkey)(input)
+}
+
+/// Check that every monkey waited on exists and that `root` waits on two
+/// monkeys, given the `input` the monkeys were parsed from.
+fn check(input: &str, monkeys: &[Monkey]) -> Result<(), Error> {
+    let names: HashMap<&str, usize> = monkeys
+        .iter()
+        .enumerate()
+        .map(|(i, m)| (m.name.as_str(), i))
+        .collect();
+    for ((i, line), m) in input.lines().enumerate().zip(monkeys) {
+        if let Some((a, b)) = m.operation.operands() {
+            let first = m.name.len() + 3;
+            for (name, column) in [(a, first), (b, line.len() - b.len() + 1)] {
+                if !names.contains_key(name) {
+                    return Err(ParseError::new(i + 1, column, name, "no such monkey").into());
+                }
+            }
+        }
+    }
+
+    let missing = |name| ParseError::new(monkeys.len() + 1, 1, "", format!("missing `{name}`"));
+    let &root = names.get("root").ok_or_else(|| missing("root"))?;
+    names.get("humn").ok_or_else(|| missing("humn"))?;
+    if monkeys[root].operation.operands().is_none() {
+        let line = input.lines().nth(root).unwrap_or_default();
+        let column = "root: ".len() + 1;
+        let token = line.get(column - 1..).unwrap_or_default();
+        return Err(
+            ParseError::new(root + 1, column, token, "`root` must wait on two monkeys").into(),
+        );
+    }
+    Ok(())
+}
+
+fn dfs_sum(tree: &HashMap<String, (Option<bool>, Operation)>, key: &str) -> isize {
+    match &tree[key].1 {
+        Operation::Sum(a, b) => dfs_sum(tree, a) + dfs_sum(tree, b),
+        Operation::Sub(a, b) => dfs_sum(tree, a) - dfs_sum(tree, b),
+        Operation::Times(a, b) => dfs_sum(tree, a) * dfs_sum(tree, b),
+        Operation::Div(a, b) => dfs_sum(tree, a) / dfs_sum(tree, b),
+        Operation::Const(i) => *i,
+    }
+}
+
+fn dfs_human_taint(tree: &mut HashMap<String, (Option<bool>, Operation)>, key: &str) -> bool {
+    if key == "humn" {
+        true
+    } else if let Some(x) = &tree[key].0 {
+        *x
+    } else {
+        let res = match &tree[key].1.clone() {
+            Operation::Sum(a, b)
+            | Operation::Sub(a, b)
+            | Operation::Times(a, b)
+            | Operation::Div(a, b) => dfs_human_taint(tree, a) || dfs_human_taint(tree, b),
+            Operation::Const(_) => false,
+        };
+        tree.insert(key.to_string(), (Some(res), tree[key].1.clone()));
+        res
+    }
+}
+
+fn dfs_recover(
+    tree: &mut HashMap<String, (Option<bool>, Operation)>,
+    key: &str,
+    number: isize,
+) -> isize {
+    if key == "humn" {
+        tree.insert(key.to_string(), (Some(true), Operation::Const(number)));
+    }
+    match &tree[key].1.clone() {
+        Operation::Sum(a, b) => {
+            if dfs_human_taint(tree, a) {
+                dfs_recover(tree, a, number - dfs_sum(tree, b))
+            } else {
+                dfs_recover(tree, b, number - dfs_sum(tree, a))
+            }
+        }
+        Operation::Sub(a, b) => {
+            if dfs_human_taint(tree, a) {
+                dfs_recover(tree, a, number + dfs_sum(tree, b))
+            } else {
+                dfs_recover(tree, b, dfs_sum(tree, a) - number)
+            }
+        }
+        Operation::Times(a, b) => {
+            if dfs_human_taint(tree, a) {
+                dfs_recover(tree, a, number / dfs_sum(tree, b))
+            } else {
+                dfs_recover(tree, b, number / dfs_sum(tree, a))
+            }
+        }
+        Operation::Div(a, b) => {
+            if dfs_human_taint(tree, a) {
+                dfs_recover(tree, a, number * dfs_sum(tree, b))
+            } else {
+                dfs_recover(tree, b, dfs_sum(tree, a) / number)
+            }
+        }
+        Operation::Const(i) => *i,
+    }
+}
+
+fn solve_p1(monkeys: &[Monkey]) -> isize {
+    let mut tree: HashMap<String, (Option<bool>, Operation)> = HashMap::new();
+    for m in monkeys.iter().cloned() {
+        tree.insert(m.name, (None, m.operation));
+    }
+
+    dfs_sum(&tree, "root")
+}
+
+fn solve_p2(monkeys: &[Monkey]) -> Result<isize, Error> {
+    let mut tree: HashMap<String, (Option<bool>, Operation)> = HashMap::new();
+    for m in monkeys.iter().cloned() {
+        tree.insert(m.name, (None, m.operation));
+    }
+
+    let (m1, m2) = match tree["root"].1.operands() {
+        Some((a, b)) => (a.to_string(), b.to_string()),
+        None => return Err(Error::Unsolvable("`root` yells a number".to_string())),
+    };
+
+    let (good, bad) = if dfs_human_taint(&mut tree, &m1) {
+        (m2, m1)
+    } else if dfs_human_taint(&mut tree, &m2) {
+        (m1, m2)
+    } else {
+        let e = "`root` does not depend on `humn`".to_string();
+        return Err(Error::Unsolvable(e));
+    };
+    let sum = dfs_sum(&tree, &good);
+
+    dfs_recover(&mut tree, &bad, sum);
+
+    match tree["humn"].1 {
+        Operation::Const(x) => Ok(x),
+        _ => Err(Error::Unsolvable("`humn` was not reached".to_string())),
+    }
+}
+
+pub fn solve(mut input: impl BufRead) -> Result<(isize, isize), Error> {
+    let mut buffer = String::new();
+    input.read_to_string(&mut buffer)?;
+    let monkeys = parse_strict(&buffer, all_input)?;
+    check(&buffer, &monkeys)?;
+
+    let p1 = solve_p1(&monkeys);
+    let p2 = solve_p2(&monkeys)?;
+
+    Ok((p1, p2))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_monkey() {
+        assert_eq!(
+            parse_monkey("root: pppw + sjmn"),
+            Ok((
+                "",
+                Monkey {
+                    name: "root".to_string(),
+                    operation: Operation::Sum("pppw".to_string(), "sjmn".to_string())
+                }
+            ))
+        );
+    }
+
+    #[test]
+    fn test_sample() {
+        let sample = "root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32";
+        assert_eq!(solve(sample.as_bytes()).unwrap(), (152, 301));
+    }
+
+    #[test]
+    fn test_check() {
+        let error = |input: &str| match solve(input.as_bytes()) {
+            Err(Error::Parse(e)) => e,
+            other => panic!("expected a parse error, got {other:?}"),
+        };
+        let e = error("root: humn + abcd\nhumn: 5");
+        assert_eq!((e.line, e.column, e.token.as_str()), (1, 14, "abcd"));
+        assert_eq!(e.message, "no such monkey");
+
+        let e = error("abcd: humn + humn\nhumn: 5");
+        assert_eq!((e.line, e.message.as_str()), (3, "missing `root`"));
+        let e = error("root: abcd * abcd\nabcd: 5");
+        assert_eq!(e.message, "missing `humn`");
+
+        let e = error("humn: 5\nroot: 7");
+        assert_eq!((e.line, e.column, e.token.as_str()), (2, 7, "7"));
+
+        match solve("root: abcd - abcd\nabcd: 3\nhumn: 5".as_bytes()) {
+            Err(Error::Unsolvable(e)) => assert_eq!(e, "`root` does not depend on `humn`"),
+            other => panic!("expected an unsolvable puzzle, got {other:?}"),
+        }
+    }
+}
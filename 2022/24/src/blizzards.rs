@@ -0,0 +1,83 @@
+use aoc_common::grid::{Edges, Grid, Pos};
+
+/// Which cells of the valley floor a blizzard covers at each minute. The
+/// blizzards wrap around, so the pattern repeats every `lcm(width, height)`
+/// minutes and only that many frames are kept, one bit per cell.
+#[derive(Clone, Debug)]
+pub struct Blizzards {
+    width: usize,
+    period: usize,
+    /// Words per frame.
+    words: usize,
+    bits: Vec<u64>,
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+impl Blizzards {
+    /// Track the blizzards on `floor`, the map without its walls.
+    pub fn new(floor: &Grid<u8>) -> Self {
+        let (width, height) = (floor.width(), floor.height());
+        let period = (width * height / gcd(width, height).max(1)).max(1);
+        let words = (width * height).div_ceil(64);
+        let mut winds = floor
+            .iter()
+            .filter_map(|(p, v)| match v {
+                b'>' => Some((p, (0, 1))),
+                b'<' => Some((p, (0, -1))),
+                b'v' => Some((p, (1, 0))),
+                b'^' => Some((p, (-1, 0))),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+
+        let mut bits = vec![0; period * words];
+        for frame in bits.chunks_mut(words) {
+            for (pos, d) in winds.iter_mut() {
+                let i = pos.0 * width + pos.1;
+                frame[i / 64] |= 1 << (i % 64);
+                *pos = floor.step(*pos, *d, Edges::Wrap).unwrap();
+            }
+        }
+        Blizzards {
+            width,
+            period,
+            words,
+            bits,
+        }
+    }
+
+    /// Minutes until the blizzards are back where they started.
+    pub fn period(&self) -> usize {
+        self.period
+    }
+
+    pub fn is_covered(&self, (x, y): Pos, t: usize) -> bool {
+        let i = x * self.width + y;
+        let frame = t % self.period * self.words;
+        self.bits[frame + i / 64] & (1 << (i % 64)) != 0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_wrap() {
+        let floor = Grid::from_rows(vec![b">..".to_vec(), b"..^".to_vec()]).unwrap();
+        let blizzards = Blizzards::new(&floor);
+        assert_eq!(blizzards.period(), 6);
+        assert!(blizzards.is_covered((0, 0), 0) && blizzards.is_covered((1, 2), 0));
+        assert!(blizzards.is_covered((0, 1), 1) && blizzards.is_covered((0, 2), 1));
+        assert!(!blizzards.is_covered((1, 2), 1));
+        assert!(blizzards.is_covered((0, 2), 2) && blizzards.is_covered((1, 2), 2));
+        assert!(blizzards.is_covered((0, 0), 6) && !blizzards.is_covered((0, 0), 5));
+    }
+}
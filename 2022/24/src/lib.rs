@@ -0,0 +1,252 @@
+use aoc_common::grid::{dijkstra, Connectivity, Edges, Grid, Pos};
+use aoc_common::{Answers, Error, ParseError, Solution};
+use std::fmt::{self, Display, Formatter};
+use std::io::BufRead;
+
+pub mod blizzards;
+
+use blizzards::Blizzards;
+
+/// Where the expedition is, as (row, column, minute modulo the blizzard
+/// period). Positions repeat every period, so this keeps the search finite.
+type State = (usize, usize, usize);
+
+fn successors(m: &Grid<u8>, blizzards: &Blizzards, (x, y, t): State) -> Vec<(State, usize)> {
+    let t = (t + 1) % blizzards.period();
+    m.neighbours((x, y), Connectivity::Four, Edges::Bounded)
+        .chain([(x, y)])
+        .filter(|&p| m[p] != b'#')
+        .filter(|&(x, y)| {
+            // Walking to goal or start is always ok
+            x == 0 || x == m.height() - 1 || !blizzards.is_covered((x - 1, y - 1), t)
+        })
+        .map(|(x, y)| ((x, y, t), 1))
+        .collect()
+}
+
+fn parse_row(line: usize, row: &str) -> Result<Vec<u8>, ParseError> {
+    row.chars()
+        .enumerate()
+        .map(|(i, c)| match u8::try_from(c) {
+            Ok(v @ (b'#' | b'.' | b'<' | b'>' | b'^' | b'v')) => Ok(v),
+            _ => Err(ParseError::new(line, i + 1, &c.to_string(), "unknown tile")),
+        })
+        .collect()
+}
+
+/// The valley map with its blizzards tracked ahead of time.
+#[derive(Clone, Debug)]
+pub struct Valley {
+    map: Grid<u8>,
+    blizzards: Blizzards,
+    pub entrance: Pos,
+    pub exit: Pos,
+}
+
+/// Minutes spent on each leg of an itinerary.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Itinerary {
+    pub legs: Vec<usize>,
+}
+
+impl Itinerary {
+    pub fn total(&self) -> usize {
+        self.legs.iter().sum()
+    }
+}
+
+/// Why an itinerary cannot be walked.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum ItineraryError {
+    /// The waypoint is a wall or lies outside the map.
+    NotOpen(Pos),
+    /// No route completes the leg with this index.
+    Unreachable(usize),
+}
+
+impl Display for ItineraryError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ItineraryError::NotOpen((x, y)) => write!(f, "waypoint {x},{y} is not open"),
+            ItineraryError::Unreachable(leg) => write!(f, "leg {} cannot be walked", leg + 1),
+        }
+    }
+}
+
+impl std::error::Error for ItineraryError {}
+
+impl From<ItineraryError> for Error {
+    fn from(e: ItineraryError) -> Self {
+        Error::Unsolvable(e.to_string())
+    }
+}
+
+impl Valley {
+    pub fn parse(input: impl BufRead) -> Result<Self, Error> {
+        let lines = input.lines();
+        let mapvec = lines
+            .map_while(|p| match p {
+                Ok(p) if p.is_empty() => None,
+                p => Some(p),
+            })
+            .enumerate()
+            .map(|(i, p)| Ok(parse_row(i + 1, &p?)?))
+            .collect::<Result<Vec<Vec<_>>, Error>>()?;
+        let height = mapvec.len();
+        let map = Grid::from_rows(mapvec).map_err(|e| {
+            let row = String::from_utf8_lossy(&e.cells);
+            ParseError::new(e.row + 1, 1, &row, "row length differs")
+        })?;
+        if height < 3 || map.width() < 3 {
+            return Err(ParseError::new(height + 1, 1, "", "valley too small").into());
+        }
+        let width = map.width();
+        let floor = map
+            .rows()
+            .skip(1)
+            .take(height - 2)
+            .map(|r| r[1..width - 1].to_vec())
+            .collect();
+        let blizzards = Blizzards::new(&Grid::from_rows(floor).unwrap());
+
+        let gap = |x: usize| {
+            let row = map.rows().nth(x).unwrap();
+            let y = row.iter().position(|&v| v == b'.');
+            y.ok_or_else(|| ParseError::new(x + 1, 1, "", "no gap in the wall"))
+        };
+        let entrance = (0, gap(0)?);
+        let exit = (height - 1, gap(height - 1)?);
+        let border =
+            |&((x, y), _): &(Pos, &u8)| x == 0 || y == 0 || x == height - 1 || y == width - 1;
+        let breach = map
+            .iter()
+            .filter(border)
+            .find(|&(p, &v)| v != b'#' && p != entrance && p != exit);
+        if let Some(((x, y), &v)) = breach {
+            let e = ParseError::new(x + 1, y + 1, &(v as char).to_string(), "expected a wall");
+            return Err(e.into());
+        }
+        Ok(Valley {
+            map,
+            blizzards,
+            entrance,
+            exit,
+        })
+    }
+
+    /// Minutes to walk from `from` to `to`, setting off at minute `t`.
+    pub fn trip(&self, from: Pos, to: Pos, t: usize) -> Option<usize> {
+        let found = dijkstra(
+            &(from.0, from.1, t % self.blizzards.period()),
+            |&p| successors(&self.map, &self.blizzards, p),
+            |p| (p.0, p.1) == to,
+        );
+        found.map(|(_, time)| time)
+    }
+
+    /// Walk through `waypoints` in order, setting off from the first one at
+    /// minute 0.
+    pub fn itinerary(&self, waypoints: &[Pos]) -> Result<Itinerary, ItineraryError> {
+        if let Some(&p) = waypoints
+            .iter()
+            .find(|&&p| self.map.get(p).is_none_or(|&v| v == b'#'))
+        {
+            return Err(ItineraryError::NotOpen(p));
+        }
+        let mut legs = vec![];
+        let mut t = 0;
+        for (i, leg) in waypoints.windows(2).enumerate() {
+            let time = self
+                .trip(leg[0], leg[1], t)
+                .ok_or(ItineraryError::Unreachable(i))?;
+            legs.push(time);
+            t += time;
+        }
+        Ok(Itinerary { legs })
+    }
+}
+
+pub fn solve(input: impl BufRead) -> Result<(usize, usize), Error> {
+    let valley = Valley::parse(input)?;
+    let (entrance, exit) = (valley.entrance, valley.exit);
+
+    let there = valley.itinerary(&[entrance, exit])?;
+    let snacks = valley.itinerary(&[entrance, exit, entrance, exit])?;
+
+    Ok((there.total(), snacks.total()))
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let (p1, p2) = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, p2))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#";
+
+    #[test]
+    fn sample_case() {
+        let result = solve(SAMPLE.as_bytes()).unwrap();
+        assert_eq!(result, (18, 54));
+    }
+
+    #[test]
+    fn test_itinerary() {
+        let valley = Valley::parse(SAMPLE.as_bytes()).unwrap();
+        let (entrance, exit) = (valley.entrance, valley.exit);
+        let trip = valley.itinerary(&[entrance, exit, entrance, exit]).unwrap();
+        assert_eq!(trip.legs, vec![18, 23, 13]);
+        assert_eq!(trip.total(), 54);
+
+        let snack = valley.itinerary(&[entrance, (2, 3), exit]).unwrap();
+        assert_eq!(snack.legs, vec![7, 11]);
+
+        assert_eq!(valley.itinerary(&[entrance]).unwrap().total(), 0);
+        assert_eq!(
+            valley.itinerary(&[entrance, (0, 0)]),
+            Err(ItineraryError::NotOpen((0, 0)))
+        );
+        assert_eq!(
+            valley.itinerary(&[entrance, (9, 9)]),
+            Err(ItineraryError::NotOpen((9, 9)))
+        );
+    }
+
+    #[test]
+    fn test_unreachable() {
+        let case = "#.###\n#.#.#\n###.#";
+        let valley = Valley::parse(case.as_bytes()).unwrap();
+        assert_eq!(
+            valley.itinerary(&[valley.entrance, valley.exit]),
+            Err(ItineraryError::Unreachable(0))
+        );
+        match solve(case.as_bytes()) {
+            Err(Error::Unsolvable(e)) => assert_eq!(e, "leg 1 cannot be walked"),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+
+    #[test]
+    fn test_side_gap() {
+        match Valley::parse("#.###\n....#\n#...#\n###.#".as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(2, 1, ".", "expected a wall")),
+            r => panic!("unexpected {r:?}"),
+        }
+        match Valley::parse("#..##\n#...#\n###.#".as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(1, 3, ".", "expected a wall")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
@@ -0,0 +1,179 @@
+use aoc_common::{Answer, Answers, Error, ParseError, Solution};
+use core::fmt;
+use std::{
+    cmp::max,
+    fmt::{Display, Formatter},
+    io::BufRead,
+    iter::Sum,
+    str::FromStr,
+};
+
+#[derive(Debug)]
+struct BalancedQuinary(Vec<i8>);
+
+#[derive(Debug, PartialEq, Eq)]
+struct ParseBalancedQuinaryError {
+    column: usize,
+    digit: char,
+}
+
+impl FromStr for BalancedQuinary {
+    type Err = ParseBalancedQuinaryError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s
+            .chars()
+            .enumerate()
+            .map(|(i, c)| match c {
+                '=' => Ok(-2),
+                '-' => Ok(-1),
+                '0' => Ok(0),
+                '1' => Ok(1),
+                '2' => Ok(2),
+                _ => Err(ParseBalancedQuinaryError {
+                    column: i + 1,
+                    digit: c,
+                }),
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BalancedQuinary(number))
+    }
+}
+
+impl Display for BalancedQuinary {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for n in self.0.iter() {
+            write!(
+                f,
+                "{}",
+                match *n {
+                    -2 => "=",
+                    -1 => "-",
+                    0 => "0",
+                    1 => "1",
+                    2 => "2",
+                    _ => "",
+                }
+            )?;
+        }
+        Ok(())
+    }
+}
+
+impl Sum for BalancedQuinary {
+    fn sum<I: ::core::iter::Iterator<Item = Self>>(iter: I) -> Self {
+        let mut maxlen = 0;
+        let nums: Vec<Self> = iter.collect();
+        for n in nums.iter() {
+            maxlen = max(maxlen, n.0.len())
+        }
+
+        let mut res = vec![0; maxlen];
+        for bq in nums {
+            let pos = maxlen - bq.0.len();
+            for (i, d) in bq.0.iter().enumerate() {
+                res[i + pos] += *d as isize
+            }
+        }
+
+        BalancedQuinary::from_unbalanced(&res)
+    }
+}
+
+impl BalancedQuinary {
+    fn from_unbalanced(n: &[isize]) -> Self {
+        let mut numbers = n.iter().rev();
+        let mut res = vec![];
+        let mut carry = 0;
+
+        loop {
+            let mut num = carry;
+            if let Some(d) = numbers.next() {
+                num += d;
+            } else if num == 0 {
+                break;
+            }
+
+            if num > 2 {
+                let trim_times = (num + 2) / 5;
+                num -= trim_times * 5;
+                carry = trim_times;
+            } else if num < -2 {
+                let trim_times = (num - 2) / 5;
+                num -= trim_times * 5;
+                carry = trim_times;
+            } else {
+                carry = 0;
+            }
+            res.push(num as i8);
+        }
+
+        res.reverse();
+        BalancedQuinary(res)
+    }
+}
+
+pub fn solve(input: impl BufRead) -> Result<String, Error> {
+    let lines = input.lines();
+    let numbers = lines
+        .map_while(|p| match p {
+            Ok(p) if p.is_empty() => None,
+            p => Some(p),
+        })
+        .enumerate()
+        .map(|(i, p)| {
+            BalancedQuinary::from_str(&p?).map_err(|e| {
+                let digit = e.digit.to_string();
+                Error::from(ParseError::new(i + 1, e.column, &digit, "unknown digit"))
+            })
+        })
+        .collect::<Result<Vec<_>, Error>>()?;
+
+    let part1 = numbers.into_iter().sum::<BalancedQuinary>();
+
+    Ok(part1.to_string())
+}
+
+pub struct Day;
+
+impl Solution for Day {
+    fn solve(&self, input: &str) -> Result<Answers, Error> {
+        let p1 = solve(input.as_bytes())?;
+        Ok(Answers::new(p1, Answer::NotApplicable))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sample_case() {
+        let case = "1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122";
+        let result = super::solve(case.as_bytes()).unwrap();
+        assert_eq!(result, "2=-1=0".to_string());
+    }
+
+    #[test]
+    fn unknown_digit() {
+        let case = "1=-0-2
+12131";
+        match super::solve(case.as_bytes()) {
+            Err(Error::Parse(e)) => assert_eq!(e, ParseError::new(2, 4, "3", "unknown digit")),
+            r => panic!("unexpected {r:?}"),
+        }
+    }
+}
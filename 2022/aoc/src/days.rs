@@ -0,0 +1,54 @@
+use aoc_common::Solution;
+
+/// Years and days known to the runner, in order.
+pub const DAYS: &[(u32, u32, &dyn Solution)] = &[
+    (2022, 1, &aoc_2022_01::Day),
+    (2022, 2, &aoc_2022_02::Day),
+    (2022, 3, &aoc_2022_03::Day),
+    (2022, 4, &aoc_2022_04::Day),
+    (2022, 5, &aoc_2022_05::Day),
+    (2022, 6, &aoc_2022_06::Day),
+    (2022, 7, &aoc_2022_07::Day),
+    (2022, 8, &aoc_2022_08::Day),
+    (2022, 9, &aoc_2022_09::Day),
+    (2022, 10, &aoc_2022_10::Day),
+    (2022, 11, &aoc_2022_11::Day),
+    (2022, 12, &aoc_2022_12::Day),
+    (2022, 13, &aoc_2022_13::Day),
+    (2022, 14, &aoc_2022_14::Day),
+    (2022, 15, &aoc_2022_15::Day),
+    (2022, 17, &aoc_2022_17::Day),
+    (2022, 18, &aoc_2022_18::Day),
+    (2022, 20, &aoc_2022_20::Day),
+    (2022, 21, &aoc_2022_21::Day),
+    (2022, 24, &aoc_2022_24::Day),
+    (2022, 25, &aoc_2022_25::Day),
+];
+
+pub fn find(year: u32, day: u32) -> Option<&'static dyn Solution> {
+    DAYS.iter()
+        .find(|(y, d, _)| *y == year && *d == day)
+        .map(|(_, _, solution)| *solution)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_common::Answers;
+
+    #[test]
+    fn test_find() {
+        assert!(find(2022, 17).is_some());
+        assert!(find(2022, 16).is_none());
+        assert!(find(2021, 1).is_none());
+    }
+
+    #[test]
+    fn test_solve() {
+        let day = find(2022, 6).unwrap();
+        assert_eq!(
+            day.solve("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap(),
+            Answers::new(7usize, 19usize)
+        );
+    }
+}
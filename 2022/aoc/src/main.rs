@@ -0,0 +1,81 @@
+use aoc_common::Answer;
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::process;
+use std::time::Instant;
+
+mod days;
+
+const USAGE: &str = "usage:
+    aoc run <year> <day> [<input file>]
+    aoc list";
+
+fn usage() -> ! {
+    eprintln!("{USAGE}");
+    process::exit(2);
+}
+
+fn read_input(path: Option<&String>) -> io::Result<String> {
+    match path {
+        Some(p) => fs::read_to_string(p),
+        None => {
+            let mut buffer = String::new();
+            io::stdin().lock().read_to_string(&mut buffer)?;
+            Ok(buffer)
+        }
+    }
+}
+
+fn run(args: &[String]) {
+    let (year, day) = match args {
+        [year, day] | [year, day, _] => match (year.parse(), day.parse()) {
+            (Ok(year), Ok(day)) => (year, day),
+            _ => usage(),
+        },
+        _ => usage(),
+    };
+
+    let Some(solution) = days::find(year, day) else {
+        eprintln!("no solution for {year} day {day}");
+        process::exit(1);
+    };
+
+    let input = read_input(args.get(2)).unwrap_or_else(|e| {
+        eprintln!("cannot read input: {e}");
+        process::exit(1);
+    });
+
+    let start = Instant::now();
+    let answers = solution.solve(&input).unwrap_or_else(|e| {
+        eprintln!("{e}");
+        process::exit(1);
+    });
+    let elapsed = start.elapsed();
+
+    print_answer(1, &answers.part1);
+    print_answer(2, &answers.part2);
+    println!("Time: {elapsed:?}");
+}
+
+fn print_answer(part: usize, answer: &Answer) {
+    match answer {
+        Answer::Glyphs(rows) => println!("Part {part}:\n{rows}"),
+        _ => println!("Part {part}: {answer}"),
+    }
+}
+
+fn list() {
+    for (year, day, _) in days::DAYS {
+        println!("{year} {day:02}");
+    }
+}
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("list") => list(),
+        _ => usage(),
+    }
+}
@@ -0,0 +1,82 @@
+use std::fmt::{self, Display, Formatter};
+
+/// The answer to one part of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    /// Rendered glyph rows, e.g. a CRT screen, one row per line.
+    Glyphs(String),
+    /// The part has no answer, e.g. part 2 of the last day.
+    NotApplicable,
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(n) => write!(f, "{n}"),
+            Answer::Text(s) | Answer::Glyphs(s) => write!(f, "{s}"),
+            Answer::NotApplicable => write!(f, "-"),
+        }
+    }
+}
+
+macro_rules! integer_answer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(n: $t) -> Self {
+                    Answer::Integer(n as i128)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i32, i64, isize, usize, u128);
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// The answers to both parts of a puzzle.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Answers {
+    pub part1: Answer,
+    pub part2: Answer,
+}
+
+impl Answers {
+    pub fn new(part1: impl Into<Answer>, part2: impl Into<Answer>) -> Self {
+        Answers {
+            part1: part1.into(),
+            part2: part2.into(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Answer::from(42usize).to_string(), "42");
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ".to_string()).to_string(), "CMZ");
+        assert_eq!(Answer::NotApplicable.to_string(), "-");
+    }
+
+    #[test]
+    fn test_answers_new() {
+        assert_eq!(
+            Answers::new(15i64, Answer::NotApplicable),
+            Answers {
+                part1: Answer::Integer(15),
+                part2: Answer::NotApplicable,
+            }
+        );
+    }
+}
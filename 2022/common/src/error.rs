@@ -0,0 +1,127 @@
+use std::fmt::{self, Display, Formatter};
+use std::io;
+
+/// Malformed puzzle input, located by line and column (both starting at 1).
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub token: String,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, token: &str, message: impl Into<String>) -> Self {
+        ParseError {
+            line,
+            column,
+            token: token.to_string(),
+            message: message.into(),
+        }
+    }
+
+    /// Describe a failure at `rest`, which must be a suffix of `input`.
+    pub fn at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = input.len() - rest.len();
+        let before = &input[..offset];
+        let line = before.matches('\n').count() + 1;
+        let column = before.len() - before.rfind('\n').map_or(0, |i| i + 1) + 1;
+        ParseError::new(line, column, first_token(rest), message)
+    }
+
+    /// Convert a nom error raised while parsing `input`.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                ParseError::at(input, e.input, format!("unexpected input ({:?})", e.code))
+            }
+            nom::Err::Incomplete(_) => ParseError::at(input, "", "incomplete input"),
+        }
+    }
+}
+
+fn first_token(rest: &str) -> &str {
+    let rest = rest.lines().next().unwrap_or_default();
+    match rest.find(char::is_whitespace) {
+        Some(0) => &rest[..rest.chars().next().map_or(0, char::len_utf8)],
+        Some(end) => &rest[..end],
+        None => rest,
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, column {}: {}",
+            self.line, self.column, self.message
+        )?;
+        if self.token.is_empty() {
+            write!(f, " at end of input")
+        } else {
+            write!(f, " at `{}`", self.token)
+        }
+    }
+}
+
+/// Error returned when a puzzle cannot be solved.
+#[derive(Debug)]
+pub enum Error {
+    Io(io::Error),
+    Parse(ParseError),
+    /// The input is well-formed but, with the given settings, describes a
+    /// puzzle that has no answer.
+    Unsolvable(String),
+}
+
+impl Display for Error {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Io(e) => write!(f, "cannot read input: {e}"),
+            Error::Parse(e) => write!(f, "cannot parse input: {e}"),
+            Error::Unsolvable(e) => write!(f, "cannot solve puzzle: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(e: io::Error) -> Self {
+        Error::Io(e)
+    }
+}
+
+impl From<ParseError> for Error {
+    fn from(e: ParseError) -> Self {
+        Error::Parse(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::bytes::complete::tag;
+
+    #[test]
+    fn test_at() {
+        let input = "abc\ndef ghi\n";
+        let err = ParseError::at(input, &input[8..], "oops");
+        assert_eq!(err, ParseError::new(2, 5, "ghi", "oops"));
+        assert_eq!(err.to_string(), "line 2, column 5: oops at `ghi`");
+
+        let err = ParseError::at(input, "", "oops");
+        assert_eq!(err, ParseError::new(3, 1, "", "oops"));
+        assert_eq!(err.to_string(), "line 3, column 1: oops at end of input");
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "foo\nbar";
+        let err = tag::<_, _, nom::error::Error<&str>>("baz")(&input[4..]).unwrap_err();
+        assert_eq!(
+            ParseError::from_nom(input, err),
+            ParseError::new(2, 1, "bar", "unexpected input (Tag)")
+        );
+    }
+}
@@ -0,0 +1,370 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::{Index, IndexMut};
+
+/// A position in a grid as (row, column).
+pub type Pos = (usize, usize);
+
+/// Which cells count as neighbours.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Connectivity {
+    /// Up, left, down and right.
+    Four,
+    /// The four above plus the diagonals.
+    Eight,
+}
+
+/// What happens when a step leaves the grid.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Edges {
+    /// There is nothing outside the grid.
+    Bounded,
+    /// Leaving one side enters the opposite side.
+    Wrap,
+}
+
+const FOUR: [(isize, isize); 4] = [(-1, 0), (0, -1), (1, 0), (0, 1)];
+const DIAGONALS: [(isize, isize); 4] = [(-1, -1), (-1, 1), (1, -1), (1, 1)];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Rows given to [`Grid::from_rows`] that are not all the same length.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RaggedRow<T> {
+    /// Index of the first row whose length differs from the first one.
+    pub row: usize,
+    pub cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(height: usize, width: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self, RaggedRow<T>> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, Vec::len);
+        let mut cells = Vec::with_capacity(width * height);
+        for (row, r) in rows.into_iter().enumerate() {
+            if r.len() != width {
+                return Err(RaggedRow { row, cells: r });
+            }
+            cells.extend(r);
+        }
+        Ok(Grid {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn get(&self, (r, c): Pos) -> Option<&T> {
+        (r < self.height && c < self.width).then(|| &self.cells[r * self.width + c])
+    }
+
+    pub fn get_mut(&mut self, (r, c): Pos) -> Option<&mut T> {
+        (r < self.height && c < self.width).then(|| &mut self.cells[r * self.width + c])
+    }
+
+    /// Every position with its cell, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        let width = self.width;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(move |(i, v)| ((i / width, i % width), v))
+    }
+
+    /// The first position holding `value`.
+    pub fn position(&self, value: &T) -> Option<Pos>
+    where
+        T: PartialEq,
+    {
+        self.iter().find(|(_, v)| *v == value).map(|(p, _)| p)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// Move `(dr, dc)` away from `pos`.
+    pub fn step(&self, (r, c): Pos, (dr, dc): (isize, isize), edges: Edges) -> Option<Pos> {
+        let (r, c) = (r as isize + dr, c as isize + dc);
+        let (h, w) = (self.height as isize, self.width as isize);
+        match edges {
+            Edges::Bounded if (0..h).contains(&r) && (0..w).contains(&c) => {
+                Some((r as usize, c as usize))
+            }
+            Edges::Bounded => None,
+            Edges::Wrap if h > 0 && w > 0 => {
+                Some((r.rem_euclid(h) as usize, c.rem_euclid(w) as usize))
+            }
+            Edges::Wrap => None,
+        }
+    }
+
+    pub fn neighbours(
+        &self,
+        pos: Pos,
+        connectivity: Connectivity,
+        edges: Edges,
+    ) -> impl Iterator<Item = Pos> + '_ {
+        let diagonals = match connectivity {
+            Connectivity::Four => &[][..],
+            Connectivity::Eight => &DIAGONALS[..],
+        };
+        FOUR.iter()
+            .chain(diagonals)
+            .filter_map(move |d| self.step(pos, *d, edges))
+    }
+}
+
+impl<T> Index<Pos> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Pos) -> &T {
+        self.get(pos).expect("position outside the grid")
+    }
+}
+
+impl<T> IndexMut<Pos> for Grid<T> {
+    fn index_mut(&mut self, pos: Pos) -> &mut T {
+        self.get_mut(pos).expect("position outside the grid")
+    }
+}
+
+/// Follow the parent links in `nodes` back from `i` to the start.
+fn reconstruct<N: Clone>(nodes: &[(N, usize)], mut i: usize) -> Vec<N> {
+    let mut path = vec![nodes[i].0.clone()];
+    while nodes[i].1 != i {
+        i = nodes[i].1;
+        path.push(nodes[i].0.clone());
+    }
+    path.reverse();
+    path
+}
+
+/// Shortest path in steps from `start` to a node satisfying `success`, both
+/// included.
+pub fn bfs<N, I>(
+    start: &N,
+    mut successors: impl FnMut(&N) -> I,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<Vec<N>>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = N>,
+{
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([0]);
+    while let Some(i) = queue.pop_front() {
+        let node = nodes[i].0.clone();
+        if success(&node) {
+            return Some(reconstruct(&nodes, i));
+        }
+        for next in successors(&node) {
+            if seen.insert(next.clone()) {
+                nodes.push((next, i));
+                queue.push_back(nodes.len() - 1);
+            }
+        }
+    }
+    None
+}
+
+/// Steps from the nearest of `sources` to every position of `grid`, or
+/// `None` where none of them leads.
+pub fn distances<T, I>(
+    grid: &Grid<T>,
+    sources: impl IntoIterator<Item = Pos>,
+    mut successors: impl FnMut(Pos) -> I,
+) -> Grid<Option<usize>>
+where
+    I: IntoIterator<Item = Pos>,
+{
+    let mut field = Grid::new(grid.height, grid.width, None);
+    let mut queue = VecDeque::new();
+    for pos in sources {
+        if field[pos].is_none() {
+            field[pos] = Some(0);
+            queue.push_back(pos);
+        }
+    }
+    while let Some(pos) = queue.pop_front() {
+        let next = field[pos].map(|d| d + 1);
+        for n in successors(pos) {
+            if field[n].is_none() {
+                field[n] = next;
+                queue.push_back(n);
+            }
+        }
+    }
+    field
+}
+
+/// Cheapest path from `start` to a node satisfying `success`, and its cost.
+pub fn dijkstra<N, I>(
+    start: &N,
+    successors: impl FnMut(&N) -> I,
+    success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, usize)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    astar(start, successors, |_| 0, success)
+}
+
+/// Like [`dijkstra`], guided by `heuristic`, which must never overestimate
+/// the remaining cost.
+pub fn astar<N, I>(
+    start: &N,
+    mut successors: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> usize,
+    mut success: impl FnMut(&N) -> bool,
+) -> Option<(Vec<N>, usize)>
+where
+    N: Eq + Hash + Clone,
+    I: IntoIterator<Item = (N, usize)>,
+{
+    let mut nodes = vec![(start.clone(), 0)];
+    let mut best = HashMap::from([(start.clone(), 0)]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(start), 0, 0))]);
+    while let Some(Reverse((_, cost, i))) = queue.pop() {
+        let node = nodes[i].0.clone();
+        if best[&node] < cost {
+            continue;
+        }
+        if success(&node) {
+            return Some((reconstruct(&nodes, i), cost));
+        }
+        for (next, step) in successors(&node) {
+            let cost = cost + step;
+            if best.get(&next).is_none_or(|&c| cost < c) {
+                best.insert(next.clone(), cost);
+                let estimate = cost + heuristic(&next);
+                nodes.push((next, i));
+                queue.push(Reverse((estimate, cost, nodes.len() - 1)));
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn maze() -> Grid<u8> {
+        let rows = ["..#..", ".##..", "....#", "#.#.."];
+        Grid::from_rows(rows.iter().map(|r| r.bytes().collect()).collect()).unwrap()
+    }
+
+    #[test]
+    fn test_from_rows() {
+        let grid = maze();
+        assert_eq!((grid.height(), grid.width()), (4, 5));
+        assert_eq!(grid[(1, 2)], b'#');
+        assert_eq!(grid.get((4, 0)), None);
+        assert_eq!(grid.position(&b'#'), Some((0, 2)));
+        assert_eq!(
+            Grid::from_rows(vec![vec![1, 2], vec![3]]),
+            Err(RaggedRow {
+                row: 1,
+                cells: vec![3]
+            })
+        );
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::new(3, 4, 0);
+        let around = |pos, c, e| grid.neighbours(pos, c, e).collect::<Vec<_>>();
+        assert_eq!(
+            around((0, 0), Connectivity::Four, Edges::Bounded),
+            vec![(1, 0), (0, 1)]
+        );
+        assert_eq!(
+            around((0, 0), Connectivity::Four, Edges::Wrap),
+            vec![(2, 0), (0, 3), (1, 0), (0, 1)]
+        );
+        assert_eq!(around((1, 1), Connectivity::Eight, Edges::Bounded).len(), 8);
+        assert_eq!(
+            around((2, 3), Connectivity::Eight, Edges::Bounded),
+            vec![(1, 3), (2, 2), (1, 2)]
+        );
+        assert_eq!(
+            around((2, 3), Connectivity::Eight, Edges::Wrap)[4..],
+            [(1, 2), (1, 0), (0, 2), (0, 0)]
+        );
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = maze();
+        let open = |p: &Pos| {
+            grid.neighbours(*p, Connectivity::Four, Edges::Bounded)
+                .filter(|n| grid[*n] == b'.')
+                .collect::<Vec<_>>()
+        };
+        let path = bfs(&(0, 0), open, |p| *p == (0, 4)).unwrap();
+        assert_eq!(path.len(), 9);
+        assert_eq!((path[0], path[8]), ((0, 0), (0, 4)));
+        assert_eq!(bfs(&(0, 0), open, |p| *p == (3, 0)), None);
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid = maze();
+        let open = |p| {
+            grid.neighbours(p, Connectivity::Four, Edges::Bounded)
+                .filter(|&n| grid[n] == b'.')
+        };
+        let field = distances(&grid, [(0, 0), (0, 4)], open);
+        assert_eq!(field[(0, 3)], Some(1));
+        assert_eq!(field[(2, 2)], Some(4));
+        assert_eq!((field[(1, 2)], field[(3, 0)]), (None, None));
+    }
+
+    #[test]
+    fn test_dijkstra_and_astar() {
+        let grid = maze();
+        // Walking through walls costs 10.
+        let cost = |p: &Pos| {
+            grid.neighbours(*p, Connectivity::Four, Edges::Bounded)
+                .map(|n| (n, if grid[n] == b'#' { 10 } else { 1 }))
+                .collect::<Vec<_>>()
+        };
+        let goal = (0, 4);
+        let (path, total) = dijkstra(&(0, 0), cost, |p| *p == goal).unwrap();
+        assert_eq!((path.len(), total), (9, 8));
+
+        let manhattan = |p: &Pos| p.0.abs_diff(goal.0) + p.1.abs_diff(goal.1);
+        let (_, total) = astar(&(0, 0), cost, manhattan, |p| *p == goal).unwrap();
+        assert_eq!(total, 8);
+        assert_eq!(dijkstra(&(0, 0), |_| vec![], |p| *p == goal), None);
+    }
+}
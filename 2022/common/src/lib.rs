@@ -0,0 +1,14 @@
+mod answer;
+mod error;
+pub mod grid;
+mod parse;
+
+pub use answer::{Answer, Answers};
+pub use error::{Error, ParseError};
+pub use parse::parse_strict;
+
+/// A puzzle day that can be driven by the `aoc` runner.
+pub trait Solution {
+    /// Solve both parts of the puzzle for the given input.
+    fn solve(&self, input: &str) -> Result<Answers, Error>;
+}
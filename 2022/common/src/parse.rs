@@ -0,0 +1,52 @@
+use crate::ParseError;
+use nom::IResult;
+
+/// Run `parser` in strict mode: the whole of `input` must be consumed, except
+/// for a trailing newline. Leftover input is reported at its first line.
+pub fn parse_strict<'a, O, F>(input: &'a str, mut parser: F) -> Result<O, ParseError>
+where
+    F: FnMut(&'a str) -> IResult<&'a str, O>,
+{
+    let (rest, output) = parser(input).map_err(|e| ParseError::from_nom(input, e))?;
+
+    let rest = rest.trim_start_matches(['\n', '\r']);
+    if rest.is_empty() {
+        return Ok(output);
+    }
+
+    let mut err = ParseError::at(input, rest, "unparsed input");
+    err.token = rest.lines().next().unwrap_or_default().to_string();
+    Err(err)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete::*, multi::separated_list1};
+
+    fn numbers(input: &str) -> IResult<&str, Vec<&str>> {
+        separated_list1(newline, digit1)(input)
+    }
+
+    #[test]
+    fn test_trailing_newline() {
+        assert_eq!(parse_strict("1\n2\n", numbers), Ok(vec!["1", "2"]));
+        assert_eq!(parse_strict("1\n2", numbers), Ok(vec!["1", "2"]));
+    }
+
+    #[test]
+    fn test_unparsed_line() {
+        assert_eq!(
+            parse_strict("1\n2\nx 3\n4\n", numbers),
+            Err(ParseError::new(3, 1, "x 3", "unparsed input"))
+        );
+    }
+
+    #[test]
+    fn test_unparsed_column() {
+        assert_eq!(
+            parse_strict("1\n2x\n", numbers),
+            Err(ParseError::new(2, 2, "x", "unparsed input"))
+        );
+    }
+}